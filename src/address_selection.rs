@@ -0,0 +1,702 @@
+use core::{
+  cmp::Ordering,
+  net::{IpAddr, Ipv6Addr},
+};
+
+use ipnet::Ipv6Net;
+
+use super::{is_link_local_ip_addr, is_loopback_ip_addr};
+
+/// An entry of an [RFC 6724] policy table.
+///
+/// [RFC 6724]: https://datatracker.ietf.org/doc/html/rfc6724#section-2.1
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PolicyEntry {
+  prefix: Ipv6Net,
+  precedence: u8,
+  label: u8,
+}
+
+impl PolicyEntry {
+  /// Creates a policy entry. IPv4 addresses are looked up as IPv4-mapped
+  /// addresses, under `::ffff:0:0/96`.
+  #[inline]
+  pub const fn new(prefix: Ipv6Net, precedence: u8, label: u8) -> Self {
+    Self {
+      prefix,
+      precedence,
+      label,
+    }
+  }
+
+  /// Returns the prefix of the entry.
+  #[inline]
+  pub const fn prefix(&self) -> Ipv6Net {
+    self.prefix
+  }
+
+  /// Returns the precedence of the entry. Higher is preferred.
+  #[inline]
+  pub const fn precedence(&self) -> u8 {
+    self.precedence
+  }
+
+  /// Returns the label of the entry.
+  #[inline]
+  pub const fn label(&self) -> u8 {
+    self.label
+  }
+}
+
+const fn entry(segments: [u16; 8], len: u8, precedence: u8, label: u8) -> PolicyEntry {
+  let [a, b, c, d, e, f, g, h] = segments;
+  PolicyEntry::new(
+    Ipv6Net::new_assert(Ipv6Addr::new(a, b, c, d, e, f, g, h), len),
+    precedence,
+    label,
+  )
+}
+
+/// The default policy table of [RFC 6724 §2.1](https://datatracker.ietf.org/doc/html/rfc6724#section-2.1).
+const DEFAULT_POLICY: &[PolicyEntry] = &[
+  entry([0, 0, 0, 0, 0, 0, 0, 1], 128, 50, 0),
+  entry([0, 0, 0, 0, 0, 0, 0, 0], 0, 40, 1),
+  entry([0, 0, 0, 0, 0, 0xffff, 0, 0], 96, 35, 4),
+  entry([0x2002, 0, 0, 0, 0, 0, 0, 0], 16, 30, 2),
+  entry([0x2001, 0, 0, 0, 0, 0, 0, 0], 32, 5, 5),
+  entry([0xfc00, 0, 0, 0, 0, 0, 0, 0], 7, 3, 13),
+  entry([0, 0, 0, 0, 0, 0, 0, 0], 96, 1, 3),
+  entry([0xfec0, 0, 0, 0, 0, 0, 0, 0], 10, 1, 11),
+  entry([0x3ffe, 0, 0, 0, 0, 0, 0, 0], 16, 1, 12),
+];
+
+/// A candidate source address for [`PolicyTable::select_source`] and
+/// [`PolicyTable::sort_destinations`], with the properties the [RFC 6724]
+/// rules look at.
+///
+/// [RFC 6724]: https://datatracker.ietf.org/doc/rfc6724/
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SourceCandidate {
+  addr: IpAddr,
+  prefix_len: u8,
+  deprecated: bool,
+  home: bool,
+  care_of: bool,
+  outgoing: bool,
+  temporary: bool,
+}
+
+impl SourceCandidate {
+  /// Creates a candidate for a preferred, public address, on a /64 for IPv6
+  /// or a /32 for IPv4.
+  #[inline]
+  pub const fn new(addr: IpAddr) -> Self {
+    Self {
+      addr,
+      prefix_len: match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 64,
+      },
+      deprecated: false,
+      home: false,
+      care_of: false,
+      outgoing: false,
+      temporary: false,
+    }
+  }
+
+  /// Sets the length of the on-link prefix of the address.
+  #[inline]
+  pub const fn with_prefix_len(mut self, prefix_len: u8) -> Self {
+    self.prefix_len = prefix_len;
+    self
+  }
+
+  /// Sets whether the address is deprecated.
+  #[inline]
+  pub const fn with_deprecated(mut self, deprecated: bool) -> Self {
+    self.deprecated = deprecated;
+    self
+  }
+
+  /// Sets whether the address is a Mobile IPv6 home address.
+  #[inline]
+  pub const fn with_home(mut self, home: bool) -> Self {
+    self.home = home;
+    self
+  }
+
+  /// Sets whether the address is a Mobile IPv6 care-of address.
+  #[inline]
+  pub const fn with_care_of(mut self, care_of: bool) -> Self {
+    self.care_of = care_of;
+    self
+  }
+
+  /// Sets whether the address is assigned to the outgoing interface.
+  #[inline]
+  pub const fn with_outgoing(mut self, outgoing: bool) -> Self {
+    self.outgoing = outgoing;
+    self
+  }
+
+  /// Sets whether the address is a temporary (privacy) address.
+  #[inline]
+  pub const fn with_temporary(mut self, temporary: bool) -> Self {
+    self.temporary = temporary;
+    self
+  }
+
+  /// Returns the address.
+  #[inline]
+  pub const fn addr(&self) -> IpAddr {
+    self.addr
+  }
+
+  /// Returns the length of the on-link prefix of the address.
+  #[inline]
+  pub const fn prefix_len(&self) -> u8 {
+    self.prefix_len
+  }
+
+  /// Returns `true` if the address is deprecated.
+  #[inline]
+  pub const fn deprecated(&self) -> bool {
+    self.deprecated
+  }
+
+  /// Returns `true` if the address is a Mobile IPv6 home address.
+  #[inline]
+  pub const fn home(&self) -> bool {
+    self.home
+  }
+
+  /// Returns `true` if the address is a Mobile IPv6 care-of address.
+  #[inline]
+  pub const fn care_of(&self) -> bool {
+    self.care_of
+  }
+
+  /// Returns `true` if the address is assigned to the outgoing interface.
+  #[inline]
+  pub const fn outgoing(&self) -> bool {
+    self.outgoing
+  }
+
+  /// Returns `true` if the address is a temporary address.
+  #[inline]
+  pub const fn temporary(&self) -> bool {
+    self.temporary
+  }
+
+  /// Returns `true` if the address is reached through an encapsulating
+  /// transition mechanism, i.e. 6to4 or Teredo.
+  #[inline]
+  const fn encapsulated(&self) -> bool {
+    match self.addr {
+      IpAddr::V4(_) => false,
+      IpAddr::V6(ip) => {
+        let s = ip.segments();
+        s[0] == 0x2002 || (s[0] == 0x2001 && s[1] == 0)
+      }
+    }
+  }
+}
+
+/// An [RFC 6724] policy table, used for default address selection.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{PolicyTable, SourceCandidate};
+/// use std::net::IpAddr;
+///
+/// let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+/// let sources = [
+///   SourceCandidate::new(ip("2001:db8:1::2")),
+///   SourceCandidate::new(ip("fe80::1")),
+///   SourceCandidate::new(ip("10.1.2.4")),
+/// ];
+///
+/// let mut destinations = [ip("10.1.2.3"), ip("2001:db8:1::1")];
+/// PolicyTable::DEFAULT.sort_destinations(&mut destinations, &sources);
+/// assert_eq!([ip("2001:db8:1::1"), ip("10.1.2.3")], destinations);
+///
+/// let source = PolicyTable::DEFAULT.select_source(ip("fe80::2"), &sources).unwrap();
+/// assert_eq!(ip("fe80::1"), source.addr());
+/// ```
+///
+/// [RFC 6724]: https://datatracker.ietf.org/doc/rfc6724/
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PolicyTable<'a>(&'a [PolicyEntry]);
+
+impl Default for PolicyTable<'static> {
+  #[inline]
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
+
+impl<'a> PolicyTable<'a> {
+  /// The default policy table of
+  /// [RFC 6724 §2.1](https://datatracker.ietf.org/doc/html/rfc6724#section-2.1).
+  ///
+  /// | Prefix          | Precedence | Label |
+  /// |-----------------|-----------:|------:|
+  /// | `::1/128`       |         50 |     0 |
+  /// | `::/0`          |         40 |     1 |
+  /// | `::ffff:0:0/96` |         35 |     4 |
+  /// | `2002::/16`     |         30 |     2 |
+  /// | `2001::/32`     |          5 |     5 |
+  /// | `fc00::/7`      |          3 |    13 |
+  /// | `::/96`         |          1 |     3 |
+  /// | `fec0::/10`     |          1 |    11 |
+  /// | `3ffe::/16`     |          1 |    12 |
+  pub const DEFAULT: PolicyTable<'static> = PolicyTable(DEFAULT_POLICY);
+
+  /// Creates a custom policy table.
+  ///
+  /// Addresses matching no entry get precedence 0 and a label distinct
+  /// from every entry, shared by all of them.
+  #[inline]
+  pub const fn new(entries: &'a [PolicyEntry]) -> Self {
+    Self(entries)
+  }
+
+  /// Returns the entries of the table.
+  #[inline]
+  pub const fn entries(&self) -> &'a [PolicyEntry] {
+    self.0
+  }
+
+  /// Returns the entry with the longest prefix matching the ip.
+  pub fn lookup(&self, ip: IpAddr) -> Option<&'a PolicyEntry> {
+    let ip = match ip {
+      IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+      IpAddr::V6(ip) => ip,
+    };
+    self
+      .0
+      .iter()
+      .filter(|entry| entry.prefix.contains(&ip))
+      .fold(None, |acc: Option<&PolicyEntry>, entry| match acc {
+        Some(a) if a.prefix.prefix_len() >= entry.prefix.prefix_len() => Some(a),
+        _ => Some(entry),
+      })
+  }
+
+  /// Returns the precedence of the ip.
+  #[inline]
+  pub fn precedence(&self, ip: IpAddr) -> u8 {
+    self.lookup(ip).map_or(0, |entry| entry.precedence)
+  }
+
+  /// Returns the label of the ip, or `None` if no entry matches.
+  #[inline]
+  pub fn label(&self, ip: IpAddr) -> Option<u8> {
+    self.lookup(ip).map(|entry| entry.label)
+  }
+
+  /// Selects the source address for the destination among the candidates
+  /// of the same address family, following the rules of
+  /// [RFC 6724 §5](https://datatracker.ietf.org/doc/html/rfc6724#section-5).
+  ///
+  /// Rule 5.5 (prefer addresses in a prefix advertised by the next-hop) is
+  /// not applied. Among equally preferred candidates, the first one wins.
+  pub fn select_source<'s>(
+    &self,
+    destination: IpAddr,
+    candidates: &'s [SourceCandidate],
+  ) -> Option<&'s SourceCandidate> {
+    candidates
+      .iter()
+      .filter(|c| c.addr.is_ipv4() == destination.is_ipv4())
+      .min_by(|a, b| self.compare_sources(destination, a, b))
+  }
+
+  /// Sorts the destinations in place, most preferred first, following the
+  /// rules of [RFC 6724 §6](https://datatracker.ietf.org/doc/html/rfc6724#section-6).
+  ///
+  /// The source of each destination is chosen with
+  /// [`select_source`](Self::select_source). Rule 9 (longest matching prefix)
+  /// is only applied to IPv6 destinations, and the sort is stable, which
+  /// implements rule 10.
+  #[inline]
+  pub fn sort_destinations(&self, destinations: &mut [IpAddr], candidates: &[SourceCandidate]) {
+    self.sort_destinations_by_key(destinations, candidates, |ip| *ip)
+  }
+
+  /// Sorts the items in place by their destination address, like
+  /// [`sort_destinations`](Self::sort_destinations).
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{PolicyTable, SourceCandidate};
+  /// use std::net::SocketAddr;
+  ///
+  /// let sources = [SourceCandidate::new("192.0.2.10".parse().unwrap())];
+  /// let mut resolved: [SocketAddr; 2] = ["[2001:db8::1]:443".parse().unwrap(), "192.0.2.1:443".parse().unwrap()];
+  /// PolicyTable::DEFAULT.sort_destinations_by_key(&mut resolved, &sources, SocketAddr::ip);
+  /// assert!(resolved[0].is_ipv4());
+  /// ```
+  pub fn sort_destinations_by_key<T, F>(
+    &self,
+    items: &mut [T],
+    candidates: &[SourceCandidate],
+    mut f: F,
+  ) where
+    F: FnMut(&T) -> IpAddr,
+  {
+    // Insertion sort: stable, allocation free, and the lists are short.
+    for i in 1..items.len() {
+      let mut j = i;
+      while j > 0 {
+        let (a, b) = (f(&items[j - 1]), f(&items[j]));
+        if self.compare_destinations(b, a, candidates) != Ordering::Less {
+          break;
+        }
+        items.swap(j - 1, j);
+        j -= 1;
+      }
+    }
+  }
+
+  /// Compares two destinations, `Less` meaning `da` is preferred.
+  fn compare_destinations(
+    &self,
+    da: IpAddr,
+    db: IpAddr,
+    candidates: &[SourceCandidate],
+  ) -> Ordering {
+    let (sa, sb) = match (
+      self.select_source(da, candidates),
+      self.select_source(db, candidates),
+    ) {
+      (Some(sa), Some(sb)) => (sa, sb),
+      // Rule 1: avoid unusable destinations.
+      (sa, sb) => return prefer(sa.is_some(), sb.is_some()),
+    };
+
+    // Rule 2: prefer matching scope.
+    prefer(scope(da) == scope(sa.addr), scope(db) == scope(sb.addr))
+      // Rule 3: avoid deprecated addresses.
+      .then_with(|| prefer(!sa.deprecated, !sb.deprecated))
+      // Rule 4: prefer home addresses.
+      .then_with(|| compare_home(sa, sb))
+      // Rule 5: prefer matching label.
+      .then_with(|| {
+        prefer(
+          self.labels_match(sa.addr, da),
+          self.labels_match(sb.addr, db),
+        )
+      })
+      // Rule 6: prefer higher precedence.
+      .then_with(|| self.precedence(db).cmp(&self.precedence(da)))
+      // Rule 7: prefer native transport.
+      .then_with(|| prefer(!sa.encapsulated(), !sb.encapsulated()))
+      // Rule 8: prefer smaller scope.
+      .then_with(|| scope(da).cmp(&scope(db)))
+      // Rule 9: use longest matching prefix.
+      .then_with(|| match (da, db) {
+        (IpAddr::V6(_), IpAddr::V6(_)) => {
+          let a = common_prefix_len(da, sa.addr).min(sa.prefix_len);
+          let b = common_prefix_len(db, sb.addr).min(sb.prefix_len);
+          b.cmp(&a)
+        }
+        _ => Ordering::Equal,
+      })
+  }
+
+  /// Compares two source candidates for the destination, `Less` meaning
+  /// `sa` is preferred.
+  fn compare_sources(&self, d: IpAddr, sa: &SourceCandidate, sb: &SourceCandidate) -> Ordering {
+    // Rule 1: prefer same address.
+    prefer(sa.addr == d, sb.addr == d)
+      // Rule 2: prefer appropriate scope.
+      .then_with(|| {
+        let (a, b, d) = (scope(sa.addr), scope(sb.addr), scope(d));
+        match a.cmp(&b) {
+          Ordering::Less if a < d => Ordering::Greater,
+          Ordering::Greater if b < d => Ordering::Less,
+          ord => ord,
+        }
+      })
+      // Rule 3: avoid deprecated addresses.
+      .then_with(|| prefer(!sa.deprecated, !sb.deprecated))
+      // Rule 4: prefer home addresses.
+      .then_with(|| compare_home(sa, sb))
+      // Rule 5: prefer outgoing interface.
+      .then_with(|| prefer(sa.outgoing, sb.outgoing))
+      // Rule 6: prefer matching label.
+      .then_with(|| prefer(self.labels_match(sa.addr, d), self.labels_match(sb.addr, d)))
+      // Rule 7: prefer temporary addresses.
+      .then_with(|| prefer(sa.temporary, sb.temporary))
+      // Rule 8: use longest matching prefix.
+      .then_with(|| {
+        let a = common_prefix_len(sa.addr, d).min(sa.prefix_len);
+        let b = common_prefix_len(sb.addr, d).min(sb.prefix_len);
+        b.cmp(&a)
+      })
+  }
+
+  #[inline]
+  fn labels_match(&self, a: IpAddr, b: IpAddr) -> bool {
+    self.label(a) == self.label(b)
+  }
+}
+
+/// Returns `Less` if only `a` holds, `Greater` if only `b` holds.
+#[inline]
+fn prefer(a: bool, b: bool) -> Ordering {
+  b.cmp(&a)
+}
+
+/// Rule 4 of both source selection and destination sorting.
+fn compare_home(sa: &SourceCandidate, sb: &SourceCandidate) -> Ordering {
+  let both = |s: &SourceCandidate| s.home && s.care_of;
+  let home = |s: &SourceCandidate| s.home && !s.care_of;
+  let care_of = |s: &SourceCandidate| !s.home && s.care_of;
+  prefer(both(sa), both(sb)).then_with(|| {
+    if home(sa) && care_of(sb) {
+      Ordering::Less
+    } else if care_of(sa) && home(sb) {
+      Ordering::Greater
+    } else {
+      Ordering::Equal
+    }
+  })
+}
+
+/// Returns the scope of the ip as defined by
+/// [RFC 6724 §3.1](https://datatracker.ietf.org/doc/html/rfc6724#section-3.1):
+/// the multicast scope value, or 0x2 (link-local), 0x5 (site-local) and 0xe
+/// (global) for unicast addresses.
+const fn scope(ip: IpAddr) -> u8 {
+  if is_loopback_ip_addr(ip) || is_link_local_ip_addr(ip) {
+    return 0x2;
+  }
+  match ip {
+    IpAddr::V4(_) => 0xe,
+    IpAddr::V6(ip) => {
+      let s = ip.segments();
+      if s[0] & 0xff00 == 0xff00 {
+        (s[0] & 0xf) as u8
+      } else if s[0] & 0xffc0 == 0xfec0 {
+        0x5
+      } else {
+        0xe
+      }
+    }
+  }
+}
+
+/// Returns the length of the longest common prefix of two addresses of the
+/// same family.
+const fn common_prefix_len(a: IpAddr, b: IpAddr) -> u8 {
+  match (a, b) {
+    (IpAddr::V4(a), IpAddr::V4(b)) => (a.to_bits() ^ b.to_bits()).leading_zeros() as u8,
+    (IpAddr::V6(a), IpAddr::V6(b)) => (a.to_bits() ^ b.to_bits()).leading_zeros() as u8,
+    _ => 0,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+  }
+
+  fn src(s: &str) -> SourceCandidate {
+    SourceCandidate::new(ip(s))
+  }
+
+  #[test]
+  fn policy_table() {
+    let table = PolicyTable::DEFAULT;
+    for (addr, precedence, label) in [
+      ("::1", 50, 0),
+      ("2001:db8::1", 40, 1),
+      ("192.0.2.1", 35, 4),
+      ("::ffff:192.0.2.1", 35, 4),
+      ("2002:c000:201::1", 30, 2),
+      ("2001::1", 5, 5),
+      ("fd00::1", 3, 13),
+      ("::192.0.2.1", 1, 3),
+      ("fec0::1", 1, 11),
+      ("3ffe::1", 1, 12),
+    ] {
+      assert_eq!(table.precedence(ip(addr)), precedence, "{addr}");
+      assert_eq!(table.label(ip(addr)), Some(label), "{addr}");
+    }
+
+    let custom = [PolicyEntry::new("2001:db8::/32".parse().unwrap(), 60, 7)];
+    let table = PolicyTable::new(&custom);
+    assert_eq!(table.precedence(ip("2001:db8::1")), 60);
+    assert_eq!(table.label(ip("2001:db9::1")), None);
+    assert_eq!(table.precedence(ip("2001:db9::1")), 0);
+  }
+
+  #[test]
+  fn source_selection() {
+    // RFC 6724 §10.1
+    for (d, candidates, expected) in [
+      (
+        "2001:db8:1::1",
+        [src("2001:db8:3::1"), src("fe80::1")],
+        "2001:db8:3::1",
+      ),
+      (
+        "ff05::1",
+        [src("2001:db8:3::1"), src("fe80::1")],
+        "2001:db8:3::1",
+      ),
+      (
+        "2001:db8:1::1",
+        [
+          src("2001:db8:1::1").with_deprecated(true),
+          src("2001:db8:2::1"),
+        ],
+        "2001:db8:1::1",
+      ),
+      (
+        "fe80::1",
+        [src("fe80::2").with_deprecated(true), src("2001:db8:1::1")],
+        "fe80::2",
+      ),
+      (
+        "2001:db8:1::1",
+        [src("2001:db8:1::2"), src("2001:db8:3::2")],
+        "2001:db8:1::2",
+      ),
+      (
+        "2001:db8:1::1",
+        [
+          src("2001:db8:1::2").with_care_of(true),
+          src("2001:db8:3::2").with_home(true),
+        ],
+        "2001:db8:3::2",
+      ),
+      (
+        "2002:c633:6401::1",
+        [
+          src("2002:c633:6401::d5e3:7953:13eb:22e8").with_temporary(true),
+          src("2001:db8:1::2"),
+        ],
+        "2002:c633:6401::d5e3:7953:13eb:22e8",
+      ),
+      (
+        "2001:db8:1::d5e3:0:0:1",
+        [
+          src("2001:db8:1::2"),
+          src("2001:db8:1::d5e3:7953:13eb:22e8").with_temporary(true),
+        ],
+        "2001:db8:1::d5e3:7953:13eb:22e8",
+      ),
+    ] {
+      let source = PolicyTable::DEFAULT.select_source(ip(d), &candidates);
+      assert_eq!(source.map(SourceCandidate::addr), Some(ip(expected)), "{d}");
+    }
+
+    assert!(PolicyTable::DEFAULT
+      .select_source(ip("192.0.2.1"), &[src("2001:db8::1")])
+      .is_none());
+
+    // Rule 8: the common prefix stops at the prefix of the source, so
+    // interface identifiers do not matter and ties keep order.
+    let candidates = [src("2001:db8:1::ffff"), src("2001:db8:1::2")];
+    let source = PolicyTable::DEFAULT.select_source(ip("2001:db8:1::1"), &candidates);
+    assert_eq!(source, Some(&candidates[0]));
+    let candidates = [
+      src("2001:db8:1::ffff").with_prefix_len(128),
+      src("2001:db8:1::2").with_prefix_len(128),
+    ];
+    let source = PolicyTable::DEFAULT.select_source(ip("2001:db8:1::1"), &candidates);
+    assert_eq!(source, Some(&candidates[1]));
+
+    // Rule 6: addresses matching no entry share the same label.
+    let custom = [PolicyEntry::new("2001:db8::/32".parse().unwrap(), 60, 7)];
+    let table = PolicyTable::new(&custom);
+    let candidates = [src("2001:db8::5"), src("2001:dba::5")];
+    let source = table.select_source(ip("2001:db9::1"), &candidates);
+    assert_eq!(source, Some(&candidates[1]));
+  }
+
+  #[test]
+  fn destination_sorting() {
+    // RFC 6724 §10.2
+    for (candidates, destinations, expected) in [
+      (
+        &[src("2001:db8:1::2"), src("fe80::1"), src("169.254.13.78")][..],
+        ["198.51.100.121", "2001:db8:1::1"],
+        ["2001:db8:1::1", "198.51.100.121"],
+      ),
+      (
+        &[src("fe80::1"), src("198.51.100.117")],
+        ["2001:db8:1::1", "198.51.100.121"],
+        ["198.51.100.121", "2001:db8:1::1"],
+      ),
+      (
+        &[src("2001:db8:1::2"), src("fe80::1"), src("10.1.2.4")],
+        ["10.1.2.3", "2001:db8:1::1"],
+        ["2001:db8:1::1", "10.1.2.3"],
+      ),
+      (
+        &[src("2001:db8:1::2"), src("fe80::2")],
+        ["2001:db8:1::1", "fe80::1"],
+        ["fe80::1", "2001:db8:1::1"],
+      ),
+      (
+        &[
+          src("2001:db8:1::2").with_care_of(true),
+          src("2001:db8:3::1").with_home(true),
+          src("fe80::2").with_care_of(true),
+        ],
+        ["fe80::1", "2001:db8:1::1"],
+        ["2001:db8:1::1", "fe80::1"],
+      ),
+      (
+        &[src("2001:db8:1::2"), src("fe80::1").with_deprecated(true)],
+        ["fe80::1", "2001:db8:1::1"],
+        ["2001:db8:1::1", "fe80::1"],
+      ),
+      (
+        &[
+          src("2001:db8:1::2"),
+          src("2001:db8:3f44::2"),
+          src("fe80::2"),
+        ],
+        ["2001:db8:3ffe::1", "2001:db8:1::1"],
+        ["2001:db8:1::1", "2001:db8:3ffe::1"],
+      ),
+      (
+        &[src("2002:c633:6401::2"), src("fe80::2")],
+        ["2001:db8:1::1", "2002:c633:6401::1"],
+        ["2002:c633:6401::1", "2001:db8:1::1"],
+      ),
+      (
+        &[
+          src("2002:c633:6401::2"),
+          src("2001:db8:1::2"),
+          src("fe80::2"),
+        ],
+        ["2002:c633:6401::1", "2001:db8:1::1"],
+        ["2001:db8:1::1", "2002:c633:6401::1"],
+      ),
+    ] {
+      let mut destinations = destinations.map(ip);
+      PolicyTable::DEFAULT.sort_destinations(&mut destinations, candidates);
+      assert_eq!(destinations, expected.map(ip));
+    }
+
+    // Rule 1 and rule 10: unusable destinations go last, ties keep order.
+    let mut destinations = ["192.0.2.1", "2001:db8::1", "2001:db8::2"].map(ip);
+    PolicyTable::DEFAULT.sort_destinations(&mut destinations, &[src("2001:db8::10")]);
+    assert_eq!(
+      destinations,
+      ["2001:db8::1", "2001:db8::2", "192.0.2.1"].map(ip)
+    );
+  }
+}
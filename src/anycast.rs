@@ -0,0 +1,247 @@
+use core::{fmt, net::Ipv6Addr};
+
+use ipnet::Ipv6Net;
+
+use super::prefix_mask;
+
+/// A reserved IPv6 subnet anycast address.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum SubnetAnycast {
+  /// Subnet-router anycast address, the subnet prefix with an all-zero
+  /// interface identifier
+  /// ([RFC 4291 §2.6.1](https://datatracker.ietf.org/doc/html/rfc4291#section-2.6.1)).
+  SubnetRouter,
+  /// Mobile IPv6 home-agents anycast address, anycast ID `0x7e`
+  /// ([RFC 6275 §10.5](https://datatracker.ietf.org/doc/html/rfc6275#section-10.5)).
+  HomeAgents,
+  /// Another reserved subnet anycast address, with its 7-bit anycast ID
+  /// ([RFC 2526 §3](https://datatracker.ietf.org/doc/html/rfc2526#section-3)).
+  Reserved(u8),
+}
+
+impl SubnetAnycast {
+  /// The anycast ID of the Mobile IPv6 home-agents anycast address.
+  pub const HOME_AGENTS_ID: u8 = 0x7e;
+
+  /// Returns the reserved subnet anycast address from its 7-bit anycast ID.
+  /// Extra bits are ignored.
+  #[inline]
+  pub const fn from_id(id: u8) -> Self {
+    match id & 0x7f {
+      Self::HOME_AGENTS_ID => Self::HomeAgents,
+      id => Self::Reserved(id),
+    }
+  }
+
+  /// Returns the 7-bit anycast ID ([RFC 2526]), or `None` for the
+  /// subnet-router anycast address.
+  ///
+  /// [RFC 2526]: https://datatracker.ietf.org/doc/rfc2526/
+  #[inline]
+  pub const fn id(&self) -> Option<u8> {
+    match self {
+      Self::SubnetRouter => None,
+      Self::HomeAgents => Some(Self::HOME_AGENTS_ID),
+      Self::Reserved(id) => Some(*id & 0x7f),
+    }
+  }
+
+  /// Returns the RFC defining the anycast address.
+  #[inline]
+  pub const fn rfc(&self) -> u32 {
+    match self {
+      Self::SubnetRouter => 4291,
+      Self::HomeAgents => 6275,
+      Self::Reserved(_) => 2526,
+    }
+  }
+
+  /// Returns the name of the anycast address.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::SubnetRouter => "Subnet-Router anycast",
+      Self::HomeAgents => "Mobile IPv6 Home-Agents anycast",
+      Self::Reserved(_) => "Reserved subnet anycast",
+    }
+  }
+
+  /// Returns this anycast address in the subnet, or `None` if the subnet is
+  /// too small to hold it (see [`subnet_anycast`]).
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::SubnetAnycast;
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let subnet = "2001:db8::/64".parse().unwrap();
+  /// assert_eq!(
+  ///   Some("2001:db8::fdff:ffff:ffff:fffe".parse::<Ipv6Addr>().unwrap()),
+  ///   SubnetAnycast::HomeAgents.addr(subnet)
+  /// );
+  /// assert_eq!(
+  ///   Some("2001:db8::".parse::<Ipv6Addr>().unwrap()),
+  ///   SubnetAnycast::SubnetRouter.addr(subnet)
+  /// );
+  /// ```
+  pub const fn addr(&self, subnet: Ipv6Net) -> Option<Ipv6Addr> {
+    let len = subnet.prefix_len();
+    let host = !prefix_mask(len);
+    let network = subnet.addr().to_bits() & !host;
+    match self.id() {
+      None if len < 127 => Some(Ipv6Addr::from_bits(network)),
+      Some(id) if len <= 120 => {
+        let iid = if len == 64 {
+          EUI64_ANYCAST
+        } else {
+          host & !0x7f
+        };
+        Some(Ipv6Addr::from_bits(network | iid | id as u128))
+      }
+      _ => None,
+    }
+  }
+}
+
+impl fmt::Display for SubnetAnycast {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Reserved(id) => write!(f, "{} 0x{:02x}", self.name(), id & 0x7f),
+      _ => f.write_str(self.name()),
+    }
+  }
+}
+
+/// Returns the kind of subnet anycast address `ip` is, in a subnet with the
+/// given prefix length, or `None` if it can be assigned to an interface.
+///
+/// The subnet-router anycast address has an all-zero interface identifier.
+/// It is not recognised in `/127` and `/128` subnets, where [RFC 6164]
+/// makes every address usable. The reserved subnet anycast addresses of
+/// [RFC 2526] are the highest 128 interface identifiers of the subnet, and
+/// for `/64` subnets, the highest 128 modified EUI-64 identifiers, with the
+/// universal/local bit cleared, i.e. `fdff:ffff:ffff:ff80` and above. They
+/// are only recognised in subnets of at least 256 addresses.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{subnet_anycast, SubnetAnycast};
+///
+/// assert_eq!(
+///   Some(SubnetAnycast::SubnetRouter),
+///   subnet_anycast("2001:db8::".parse().unwrap(), 64)
+/// );
+/// assert_eq!(
+///   Some(SubnetAnycast::HomeAgents),
+///   subnet_anycast("2001:db8::fdff:ffff:ffff:fffe".parse().unwrap(), 64)
+/// );
+/// assert_eq!(
+///   Some(SubnetAnycast::Reserved(0x7f)),
+///   subnet_anycast("2001:db8::ffff".parse().unwrap(), 112)
+/// );
+/// assert_eq!(None, subnet_anycast("2001:db8::ffff:ffff:ffff:ffff".parse().unwrap(), 64));
+/// ```
+///
+/// [RFC 2526]: https://datatracker.ietf.org/doc/rfc2526/
+/// [RFC 6164]: https://datatracker.ietf.org/doc/rfc6164/
+pub const fn subnet_anycast(ip: Ipv6Addr, prefix_len: u8) -> Option<SubnetAnycast> {
+  if prefix_len > 128 {
+    return None;
+  }
+
+  let host = !prefix_mask(prefix_len);
+  let iid = ip.to_bits() & host;
+  if iid == 0 {
+    return if prefix_len < 127 {
+      Some(SubnetAnycast::SubnetRouter)
+    } else {
+      None
+    };
+  }
+
+  if prefix_len > 120 {
+    return None;
+  }
+
+  let reserved = if prefix_len == 64 {
+    EUI64_ANYCAST
+  } else {
+    host & !0x7f
+  };
+  if iid & !0x7f == reserved {
+    Some(SubnetAnycast::from_id(iid as u8))
+  } else {
+    None
+  }
+}
+
+/// The modified EUI-64 interface identifier of anycast ID 0.
+const EUI64_ANYCAST: u128 = 0xfdff_ffff_ffff_ff80;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  fn ipv6(s: &str) -> Ipv6Addr {
+    s.parse().unwrap()
+  }
+
+  #[test]
+  fn anycast() {
+    for (ip, len, anycast) in [
+      ("2001:db8::", 64, Some(SubnetAnycast::SubnetRouter)),
+      ("2001:db8::1", 64, None),
+      (
+        "2001:db8::fdff:ffff:ffff:ff80",
+        64,
+        Some(SubnetAnycast::Reserved(0)),
+      ),
+      ("2001:db8::fdff:ffff:ffff:ff7f", 64, None),
+      (
+        "2001:db8::fdff:ffff:ffff:fffe",
+        64,
+        Some(SubnetAnycast::HomeAgents),
+      ),
+      (
+        "2001:db8::fdff:ffff:ffff:ffff",
+        64,
+        Some(SubnetAnycast::Reserved(0x7f)),
+      ),
+      ("2001:db8::ffff:ffff:ffff:fffe", 64, None),
+      ("2001:db8::ff80", 112, Some(SubnetAnycast::Reserved(0))),
+      ("2001:db8::fffe", 112, Some(SubnetAnycast::HomeAgents)),
+      ("2001:db8::ff7f", 112, None),
+      (
+        "2001:db8:0:ffff:ffff:ffff:ffff:fffe",
+        48,
+        Some(SubnetAnycast::HomeAgents),
+      ),
+      ("2001:db8::fe", 120, Some(SubnetAnycast::HomeAgents)),
+      ("2001:db8::7e", 121, None),
+      ("2001:db8::", 126, Some(SubnetAnycast::SubnetRouter)),
+      ("2001:db8::", 127, None),
+      ("2001:db8::", 128, None),
+      ("2001:db8::", 129, None),
+    ] {
+      let ip = ipv6(ip);
+      assert_eq!(subnet_anycast(ip, len), anycast, "{ip}/{len}");
+      if let (Some(anycast), Ok(subnet)) = (anycast, Ipv6Net::new(ip, len)) {
+        assert_eq!(anycast.addr(subnet), Some(ip), "{ip}/{len}");
+      }
+    }
+
+    let subnet = "2001:db8::/127".parse().unwrap();
+    assert_eq!(SubnetAnycast::SubnetRouter.addr(subnet), None);
+    assert_eq!(SubnetAnycast::from_id(0xfe), SubnetAnycast::HomeAgents);
+    assert_eq!(SubnetAnycast::HomeAgents.id(), Some(0x7e));
+    assert_eq!(SubnetAnycast::SubnetRouter.id(), None);
+    assert_eq!(
+      SubnetAnycast::Reserved(5).to_string(),
+      "Reserved subnet anycast 0x05"
+    );
+  }
+}
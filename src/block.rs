@@ -0,0 +1,206 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::Contains;
+
+/// An entry of the IANA IPv4 or IPv6 Special-Purpose Address Registry.
+///
+/// Each entry carries the information listed in
+/// [RFC 6890 §2.2.1](https://datatracker.ietf.org/doc/html/rfc6890#section-2.2.1).
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::RFC6890;
+/// use std::net::Ipv4Addr;
+///
+/// let block = RFC6890.block(&Ipv4Addr::new(169, 254, 1, 1)).unwrap();
+/// assert_eq!("Link Local", block.name());
+/// assert_eq!(3927, block.rfc());
+/// assert!(block.source());
+/// assert!(!block.forwardable());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Block {
+  pub(crate) net: IpNet,
+  pub(crate) name: &'static str,
+  pub(crate) rfc: u32,
+  pub(crate) section: Option<&'static str>,
+  pub(crate) allocated: &'static str,
+  pub(crate) terminated: Option<&'static str>,
+  pub(crate) source: bool,
+  pub(crate) destination: bool,
+  pub(crate) forwardable: bool,
+  pub(crate) global: Option<bool>,
+  pub(crate) reserved_by_protocol: bool,
+}
+
+impl Block {
+  /// Returns the address block.
+  #[inline]
+  pub const fn net(&self) -> IpNet {
+    self.net
+  }
+
+  /// Returns the descriptive name of the address block.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Returns the RFC through which the address block was requested.
+  #[inline]
+  pub const fn rfc(&self) -> u32 {
+    self.rfc
+  }
+
+  /// Returns the section of the RFC defining the address block, if the
+  /// registry names one.
+  #[inline]
+  pub const fn section(&self) -> Option<&'static str> {
+    self.section
+  }
+
+  /// Returns the allocation date of the address block, formatted as `YYYY-MM`.
+  #[inline]
+  pub const fn allocated(&self) -> &'static str {
+    self.allocated
+  }
+
+  /// Returns the termination date of the address block, formatted as `YYYY-MM`.
+  ///
+  /// This is only set for limited-use allocations.
+  #[inline]
+  pub const fn terminated(&self) -> Option<&'static str> {
+    self.terminated
+  }
+
+  /// Returns `true` if an address from the block is valid as the source
+  /// address of an IP datagram that transits two devices.
+  #[inline]
+  pub const fn source(&self) -> bool {
+    self.source
+  }
+
+  /// Returns `true` if an address from the block is valid as the destination
+  /// address of an IP datagram that transits two devices.
+  #[inline]
+  pub const fn destination(&self) -> bool {
+    self.destination
+  }
+
+  /// Returns `true` if a router may forward an IP datagram whose destination
+  /// address is drawn from the block between external interfaces.
+  #[inline]
+  pub const fn forwardable(&self) -> bool {
+    self.forwardable
+  }
+
+  /// Returns `true` if an IP datagram whose destination address is drawn from
+  /// the block is forwardable beyond a specified administrative domain.
+  ///
+  /// Returns `None` when the registry marks the value as not applicable
+  /// (e.g. `2002::/16`, see [RFC 3056](https://datatracker.ietf.org/doc/rfc3056/)).
+  #[inline]
+  pub const fn global(&self) -> Option<bool> {
+    self.global
+  }
+
+  /// Returns `true` if the block is reserved by IP itself, i.e. compliant
+  /// implementations must process packets to or from it in a special way.
+  #[inline]
+  pub const fn reserved_by_protocol(&self) -> bool {
+    self.reserved_by_protocol
+  }
+
+  /// Returns `true` if the ip is contained by the [`Block`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::RFC6890;
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let block = RFC6890.block(&Ipv6Addr::LOCALHOST).unwrap();
+  /// assert!(block.contains(&Ipv6Addr::LOCALHOST));
+  /// assert!(!block.contains(&Ipv6Addr::UNSPECIFIED));
+  /// ```
+  #[inline]
+  pub fn contains<T>(&self, ip: &T) -> bool
+  where
+    Self: Contains<T>,
+  {
+    Contains::contains(self, ip)
+  }
+}
+
+/// Returns the block with the longest prefix among those containing `ip`.
+pub(crate) fn most_specific<'a, T>(
+  blocks: impl Iterator<Item = &'a Block>,
+  ip: &T,
+) -> Option<&'a Block>
+where
+  Block: Contains<T>,
+{
+  blocks
+    .filter(|b| b.contains(ip))
+    .fold(None, |acc: Option<&Block>, b| match acc {
+      Some(a) if a.net.prefix_len() >= b.net.prefix_len() => Some(a),
+      _ => Some(b),
+    })
+}
+
+impl Contains<IpNet> for Block {
+  #[inline]
+  fn contains(&self, ip: &IpNet) -> bool {
+    self.net.contains(ip)
+  }
+}
+
+impl Contains<IpAddr> for Block {
+  #[inline]
+  fn contains(&self, ip: &IpAddr) -> bool {
+    self.net.contains(ip)
+  }
+}
+
+impl Contains<Ipv4Net> for Block {
+  #[inline]
+  fn contains(&self, ip: &Ipv4Net) -> bool {
+    match self.net {
+      IpNet::V4(net) => net.contains(ip),
+      IpNet::V6(_) => false,
+    }
+  }
+}
+
+impl Contains<Ipv4Addr> for Block {
+  #[inline]
+  fn contains(&self, ip: &Ipv4Addr) -> bool {
+    match self.net {
+      IpNet::V4(net) => net.contains(ip),
+      IpNet::V6(_) => false,
+    }
+  }
+}
+
+impl Contains<Ipv6Net> for Block {
+  #[inline]
+  fn contains(&self, ip: &Ipv6Net) -> bool {
+    match self.net {
+      IpNet::V4(_) => false,
+      IpNet::V6(net) => net.contains(ip),
+    }
+  }
+}
+
+impl Contains<Ipv6Addr> for Block {
+  #[inline]
+  fn contains(&self, ip: &Ipv6Addr) -> bool {
+    match self.net {
+      IpNet::V4(_) => false,
+      IpNet::V6(net) => net.contains(ip),
+    }
+  }
+}
@@ -0,0 +1,371 @@
+use core::{fmt, net::IpAddr};
+
+use super::{
+  embedded_ipv4, is_benchmark_ip_addr, is_broadcast_ip_addr, is_documentation_ip_addr,
+  is_global_ip_addr, is_link_local_ip_addr, is_loopback_ip_addr, is_private_ipv4_addr,
+  is_shared_ip_addr, is_unique_local_ipv6_addr, is_unspecified_ip_addr, Embedding, Ipv4Multicast,
+  Ipv6Multicast, MulticastScope,
+};
+
+/// The scope of an address, from the smallest to the largest.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum AddressScope {
+  /// Valid on the node only.
+  Node,
+  /// Valid on the link only.
+  Link,
+  /// Valid within a site or an administrative domain.
+  Site,
+  /// Valid on the Internet.
+  Global,
+}
+
+impl AddressScope {
+  /// Returns the scope of the multicast scope.
+  #[inline]
+  pub const fn of_multicast(scope: MulticastScope) -> Self {
+    match scope.value() {
+      0x0 | 0x1 => Self::Node,
+      0x2 => Self::Link,
+      0xe | 0xf => Self::Global,
+      _ => Self::Site,
+    }
+  }
+
+  /// Returns the name of the scope.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Node => "node",
+      Self::Link => "link",
+      Self::Site => "site",
+      Self::Global => "global",
+    }
+  }
+}
+
+impl fmt::Display for AddressScope {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+/// The class of an IP address, returned by [`classify`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum AddressClass {
+  /// The unspecified address, `0.0.0.0` or `::`.
+  Unspecified,
+  /// Loopback, `127.0.0.0/8` or `::1`.
+  Loopback,
+  /// The limited broadcast address `255.255.255.255`.
+  Broadcast,
+  /// Multicast, with the scope of the group.
+  Multicast(MulticastScope),
+  /// An IPv6 address carrying an IPv4 address through a translation or
+  /// tunneling mechanism, see [`embedded_ipv4`]. IPv4-mapped addresses and
+  /// ISATAP interface identifiers are not translation addresses.
+  Translation {
+    /// The mechanism carrying the IPv4 address.
+    embedding: Embedding,
+    /// The scope of the class of the IPv4 address.
+    scope: AddressScope,
+  },
+  /// Link-local, `169.254.0.0/16` or `fe80::/10`.
+  LinkLocal,
+  /// RFC 1918 private-use IPv4.
+  Private,
+  /// RFC 4193 unique-local IPv6, `fc00::/7`.
+  UniqueLocal,
+  /// RFC 6598 shared address space, `100.64.0.0/10`.
+  Shared,
+  /// Reserved for documentation.
+  Documentation,
+  /// Reserved for benchmarking.
+  Benchmarking,
+  /// Globally reachable unicast.
+  GlobalUnicast,
+  /// Any other address, not globally reachable, e.g. `240.0.0.0/4` or
+  /// `0.0.0.0/8`.
+  Reserved,
+}
+
+impl AddressClass {
+  /// Returns the scope of the class. Translation addresses have the scope
+  /// of the IPv4 address they carry.
+  #[inline]
+  pub const fn scope(&self) -> AddressScope {
+    match self {
+      Self::Unspecified | Self::Loopback => AddressScope::Node,
+      Self::Broadcast | Self::LinkLocal => AddressScope::Link,
+      Self::Multicast(scope) => AddressScope::of_multicast(*scope),
+      Self::Translation { scope, .. } => *scope,
+      Self::Private
+      | Self::UniqueLocal
+      | Self::Shared
+      | Self::Documentation
+      | Self::Benchmarking
+      | Self::Reserved => AddressScope::Site,
+      Self::GlobalUnicast => AddressScope::Global,
+    }
+  }
+
+  /// Returns the name of the class, without the multicast scope or the
+  /// translation mechanism.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Unspecified => "unspecified",
+      Self::Loopback => "loopback",
+      Self::Broadcast => "broadcast",
+      Self::Multicast(_) => "multicast",
+      Self::Translation { .. } => "translation",
+      Self::LinkLocal => "link-local",
+      Self::Private => "private",
+      Self::UniqueLocal => "unique-local",
+      Self::Shared => "shared",
+      Self::Documentation => "documentation",
+      Self::Benchmarking => "benchmarking",
+      Self::GlobalUnicast => "global unicast",
+      Self::Reserved => "reserved",
+    }
+  }
+}
+
+impl fmt::Display for AddressClass {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Multicast(scope) => write!(f, "{} {scope}", self.name()),
+      Self::Translation { embedding, .. } => write!(f, "{} {embedding}", self.name()),
+      _ => f.write_str(self.name()),
+    }
+  }
+}
+
+/// Returns the class of the IP address.
+///
+/// IPv4-mapped addresses are classified as the IPv4 address they map, so
+/// `::ffff:10.0.0.1` is private. Otherwise, the first matching class wins,
+/// in this order:
+///
+/// 1. [`Unspecified`](AddressClass::Unspecified)
+/// 2. [`Loopback`](AddressClass::Loopback)
+/// 3. [`Broadcast`](AddressClass::Broadcast)
+/// 4. [`Multicast`](AddressClass::Multicast)
+/// 5. [`Translation`](AddressClass::Translation), so `64:ff9b::a00:1` is a
+///    translation address, not a private one, with the scope of `10.0.0.1`
+/// 6. [`LinkLocal`](AddressClass::LinkLocal)
+/// 7. [`Private`](AddressClass::Private)
+/// 8. [`UniqueLocal`](AddressClass::UniqueLocal)
+/// 9. [`Shared`](AddressClass::Shared)
+/// 10. [`Documentation`](AddressClass::Documentation)
+/// 11. [`Benchmarking`](AddressClass::Benchmarking)
+/// 12. [`GlobalUnicast`](AddressClass::GlobalUnicast), if the address is
+///     globally reachable, see [`Ipv4AddrExt::is_global`](crate::Ipv4AddrExt::is_global)
+/// 13. [`Reserved`](AddressClass::Reserved) otherwise
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{classify, AddressClass, AddressScope, Embedding, MulticastScope};
+///
+/// let class = classify("10.1.2.3".parse().unwrap());
+/// assert_eq!(AddressClass::Private, class);
+/// assert_eq!(AddressScope::Site, class.scope());
+///
+/// let class = classify("ff02::fb".parse().unwrap());
+/// assert_eq!(AddressClass::Multicast(MulticastScope::LinkLocal), class);
+/// assert_eq!("multicast link-local", class.to_string());
+///
+/// let class = classify("64:ff9b::808:808".parse().unwrap());
+/// assert_eq!(
+///   AddressClass::Translation { embedding: Embedding::Nat64, scope: AddressScope::Global },
+///   class
+/// );
+/// assert_eq!(AddressClass::Loopback, classify("::ffff:127.0.0.1".parse().unwrap()));
+///
+/// assert_eq!(AddressClass::GlobalUnicast, classify("8.8.8.8".parse().unwrap()));
+/// assert_eq!(AddressClass::Reserved, classify("240.0.0.1".parse().unwrap()));
+/// ```
+pub fn classify(ip: IpAddr) -> AddressClass {
+  let embedded = match ip {
+    IpAddr::V6(ip) => embedded_ipv4(ip),
+    IpAddr::V4(_) => None,
+  };
+  if let Some((ip, Embedding::Mapped)) = embedded {
+    return classify(IpAddr::V4(ip));
+  }
+
+  if is_unspecified_ip_addr(ip) {
+    return AddressClass::Unspecified;
+  }
+  if is_loopback_ip_addr(ip) {
+    return AddressClass::Loopback;
+  }
+  if is_broadcast_ip_addr(ip) {
+    return AddressClass::Broadcast;
+  }
+
+  let multicast = match ip {
+    IpAddr::V4(ip) => Ipv4Multicast::new(ip).map(|group| group.scope()),
+    IpAddr::V6(ip) => Ipv6Multicast::new(ip).map(|group| group.scope()),
+  };
+  if let Some(scope) = multicast {
+    return AddressClass::Multicast(scope);
+  }
+
+  match embedded {
+    Some((_, Embedding::Isatap)) | None => {}
+    Some((ip, embedding)) => {
+      return AddressClass::Translation {
+        embedding,
+        scope: classify(IpAddr::V4(ip)).scope(),
+      }
+    }
+  }
+
+  if is_link_local_ip_addr(ip) {
+    return AddressClass::LinkLocal;
+  }
+  match ip {
+    IpAddr::V4(ip) if is_private_ipv4_addr(ip) => return AddressClass::Private,
+    IpAddr::V6(ip) if is_unique_local_ipv6_addr(ip) => return AddressClass::UniqueLocal,
+    _ => {}
+  }
+  if is_shared_ip_addr(ip) {
+    return AddressClass::Shared;
+  }
+  if is_documentation_ip_addr(ip) {
+    return AddressClass::Documentation;
+  }
+  if is_benchmark_ip_addr(ip) {
+    return AddressClass::Benchmarking;
+  }
+  if is_global_ip_addr(ip) {
+    AddressClass::GlobalUnicast
+  } else {
+    AddressClass::Reserved
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  #[test]
+  fn classes() {
+    use AddressClass::*;
+
+    for (ip, class, scope) in [
+      ("0.0.0.0", Unspecified, AddressScope::Node),
+      ("::", Unspecified, AddressScope::Node),
+      ("127.0.0.53", Loopback, AddressScope::Node),
+      ("::1", Loopback, AddressScope::Node),
+      ("255.255.255.255", Broadcast, AddressScope::Link),
+      (
+        "224.0.0.251",
+        Multicast(MulticastScope::LinkLocal),
+        AddressScope::Link,
+      ),
+      (
+        "239.255.0.1",
+        Multicast(MulticastScope::SiteLocal),
+        AddressScope::Site,
+      ),
+      (
+        "ff01::1",
+        Multicast(MulticastScope::InterfaceLocal),
+        AddressScope::Node,
+      ),
+      (
+        "ff0e::101",
+        Multicast(MulticastScope::Global),
+        AddressScope::Global,
+      ),
+      ("::ffff:10.0.0.1", Private, AddressScope::Site),
+      ("::ffff:127.0.0.1", Loopback, AddressScope::Node),
+      ("::ffff:8.8.8.8", GlobalUnicast, AddressScope::Global),
+      (
+        "::2",
+        Translation {
+          embedding: Embedding::Compatible,
+          scope: AddressScope::Site,
+        },
+        AddressScope::Site,
+      ),
+      (
+        "::127.0.0.1",
+        Translation {
+          embedding: Embedding::Compatible,
+          scope: AddressScope::Node,
+        },
+        AddressScope::Node,
+      ),
+      (
+        "64:ff9b::a00:1",
+        Translation {
+          embedding: Embedding::Nat64,
+          scope: AddressScope::Site,
+        },
+        AddressScope::Site,
+      ),
+      (
+        "2002:c000:201::1",
+        Translation {
+          embedding: Embedding::SixToFour,
+          scope: AddressScope::Site,
+        },
+        AddressScope::Site,
+      ),
+      (
+        "2002:808:808::1",
+        Translation {
+          embedding: Embedding::SixToFour,
+          scope: AddressScope::Global,
+        },
+        AddressScope::Global,
+      ),
+      (
+        "2001:0:4136:e378:8000:63bf:3fff:fdd2",
+        Translation {
+          embedding: Embedding::Teredo,
+          scope: AddressScope::Site,
+        },
+        AddressScope::Site,
+      ),
+      ("169.254.1.1", LinkLocal, AddressScope::Link),
+      ("fe80::5efe:a00:1", LinkLocal, AddressScope::Link),
+      ("192.168.1.1", Private, AddressScope::Site),
+      ("fd12:3456:789a::1", UniqueLocal, AddressScope::Site),
+      ("100.64.0.1", Shared, AddressScope::Site),
+      ("203.0.113.9", Documentation, AddressScope::Site),
+      ("3fff::1", Documentation, AddressScope::Site),
+      ("198.18.0.1", Benchmarking, AddressScope::Site),
+      ("2001:2::1", Benchmarking, AddressScope::Site),
+      ("1.1.1.1", GlobalUnicast, AddressScope::Global),
+      ("192.0.0.9", GlobalUnicast, AddressScope::Global),
+      ("2a00:1450::1", GlobalUnicast, AddressScope::Global),
+      ("0.1.2.3", Reserved, AddressScope::Site),
+      ("192.0.0.1", Reserved, AddressScope::Site),
+      ("240.0.0.1", Reserved, AddressScope::Site),
+      ("100::1", Reserved, AddressScope::Site),
+    ] {
+      let ip: IpAddr = ip.parse().unwrap();
+      assert_eq!(classify(ip), class, "{ip}");
+      assert_eq!(class.scope(), scope, "{ip}");
+    }
+
+    assert_eq!(GlobalUnicast.to_string(), "global unicast");
+    assert_eq!(
+      Translation {
+        embedding: Embedding::Nat64,
+        scope: AddressScope::Global
+      }
+      .to_string(),
+      "translation NAT64"
+    );
+    assert!(AddressScope::Link < AddressScope::Global);
+  }
+}
@@ -0,0 +1,166 @@
+use core::{
+  fmt,
+  net::{Ipv4Addr, Ipv6Addr},
+};
+
+/// The mechanism by which an IPv4 address is carried in an IPv6 address.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Embedding {
+  /// IPv4-mapped address in `::ffff:0:0/96`
+  /// ([RFC 4291 §2.5.5.2](https://datatracker.ietf.org/doc/html/rfc4291#section-2.5.5.2)).
+  Mapped,
+  /// Deprecated IPv4-compatible address in `::/96`
+  /// ([RFC 4291 §2.5.5.1](https://datatracker.ietf.org/doc/html/rfc4291#section-2.5.5.1)).
+  Compatible,
+  /// NAT64 address in the well-known prefix `64:ff9b::/96`
+  /// ([RFC 6052 §2.1](https://datatracker.ietf.org/doc/html/rfc6052#section-2.1)).
+  Nat64,
+  /// 6to4 address in `2002::/16`, carrying the IPv4 address of the site
+  /// ([RFC 3056 §2](https://datatracker.ietf.org/doc/html/rfc3056#section-2)).
+  SixToFour,
+  /// Teredo address in `2001::/32`, carrying the obfuscated IPv4 address of
+  /// the client ([RFC 4380 §4](https://datatracker.ietf.org/doc/html/rfc4380#section-4)).
+  /// See [`TeredoAddr`](crate::TeredoAddr) for the other fields.
+  Teredo,
+  /// ISATAP interface identifier `0:5efe:a.b.c.d` or `200:5efe:a.b.c.d`
+  /// ([RFC 5214 §6.1](https://datatracker.ietf.org/doc/html/rfc5214#section-6.1)).
+  Isatap,
+}
+
+impl Embedding {
+  /// Returns the RFC defining the embedding.
+  #[inline]
+  pub const fn rfc(&self) -> u32 {
+    match self {
+      Self::Mapped | Self::Compatible => 4291,
+      Self::Nat64 => 6052,
+      Self::SixToFour => 3056,
+      Self::Teredo => 4380,
+      Self::Isatap => 5214,
+    }
+  }
+
+  /// Returns the name of the embedding.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Mapped => "IPv4-mapped",
+      Self::Compatible => "IPv4-compatible",
+      Self::Nat64 => "NAT64",
+      Self::SixToFour => "6to4",
+      Self::Teredo => "Teredo",
+      Self::Isatap => "ISATAP",
+    }
+  }
+}
+
+impl fmt::Display for Embedding {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+/// Returns the IPv4 address embedded in the IPv6 address, and the mechanism
+/// embedding it.
+///
+/// The prefixes are checked first, so e.g. a 6to4 address with an ISATAP
+/// interface identifier yields [`Embedding::SixToFour`]. `::` and `::1` are
+/// not IPv4-compatible addresses. For Teredo, the de-obfuscated client
+/// address is returned.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{embedded_ipv4, Embedding};
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+///
+/// let ip: Ipv6Addr = "64:ff9b::c000:221".parse().unwrap();
+/// assert_eq!(
+///   Some((Ipv4Addr::new(192, 0, 2, 33), Embedding::Nat64)),
+///   embedded_ipv4(ip)
+/// );
+///
+/// let ip: Ipv6Addr = "fe80::5efe:a00:1".parse().unwrap();
+/// assert_eq!(
+///   Some((Ipv4Addr::new(10, 0, 0, 1), Embedding::Isatap)),
+///   embedded_ipv4(ip)
+/// );
+///
+/// assert_eq!(None, embedded_ipv4(Ipv6Addr::LOCALHOST));
+/// ```
+pub const fn embedded_ipv4(ip: Ipv6Addr) -> Option<(Ipv4Addr, Embedding)> {
+  let s = ip.segments();
+  let o = ip.octets();
+  let low = Ipv4Addr::new(o[12], o[13], o[14], o[15]);
+  Some(match s {
+    [0, 0, 0, 0, 0, 0xffff, _, _] => (low, Embedding::Mapped),
+    [0, 0, 0, 0, 0, 0, hi, lo] if hi != 0 || lo > 1 => (low, Embedding::Compatible),
+    [0x64, 0xff9b, 0, 0, 0, 0, _, _] => (low, Embedding::Nat64),
+    [0x2002, _, _, _, _, _, _, _] => (Ipv4Addr::new(o[2], o[3], o[4], o[5]), Embedding::SixToFour),
+    [0x2001, 0, _, _, _, _, _, _] => (
+      Ipv4Addr::new(!o[12], !o[13], !o[14], !o[15]),
+      Embedding::Teredo,
+    ),
+    _ if is_isatap_iid(ip) => (low, Embedding::Isatap),
+    _ => return None,
+  })
+}
+
+/// Returns `true` if the interface identifier is an ISATAP one,
+/// `0:5efe:a.b.c.d` or `200:5efe:a.b.c.d`, with the `u` bit either way.
+#[inline]
+pub(crate) const fn is_isatap_iid(ip: Ipv6Addr) -> bool {
+  let s = ip.segments();
+  s[4] & 0xfdff == 0 && s[5] == 0x5efe
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn embedded() {
+    for (ip, v4, embedding) in [
+      ("::ffff:192.0.2.1", "192.0.2.1", Embedding::Mapped),
+      ("::192.0.2.1", "192.0.2.1", Embedding::Compatible),
+      ("::2", "0.0.0.2", Embedding::Compatible),
+      ("64:ff9b::192.0.2.1", "192.0.2.1", Embedding::Nat64),
+      ("2002:c000:201::1", "192.0.2.1", Embedding::SixToFour),
+      (
+        "2002:c000:201::5efe:a00:1",
+        "192.0.2.1",
+        Embedding::SixToFour,
+      ),
+      (
+        "2001:0:4136:e378:8000:63bf:3fff:fdd2",
+        "192.0.2.45",
+        Embedding::Teredo,
+      ),
+      ("fe80::5efe:c000:201", "192.0.2.1", Embedding::Isatap),
+      (
+        "2001:db8::200:5efe:c000:201",
+        "192.0.2.1",
+        Embedding::Isatap,
+      ),
+    ] {
+      let ip: Ipv6Addr = ip.parse().unwrap();
+      assert_eq!(
+        embedded_ipv4(ip),
+        Some((v4.parse().unwrap(), embedding)),
+        "{ip}"
+      );
+    }
+
+    for ip in [
+      "::",
+      "::1",
+      "::ffff:0:0:1",
+      "64:ff9b:1::c000:201",
+      "2001:db8::c000:201",
+      "2001:db8::100:5efe:c000:201",
+    ] {
+      assert_eq!(embedded_ipv4(ip.parse().unwrap()), None, "{ip}");
+    }
+  }
+}
@@ -54,6 +54,7 @@ pub const FORWARDING_BLACKLIST: RFC = RFC {
     IPV4_1, IPV4_2, IPV4_3, IPV4_4, IPV4_5, IPV4_6, IPV4_7, IPV4_8, IPV4_9,
   ],
   ipv6_nets: &[IPV6_1, IPV6_2, IPV6_3, IPV6_4, IPV6_5, IPV6_6],
+  blocks: &[],
 };
 
 #[test]
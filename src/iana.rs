@@ -0,0 +1,995 @@
+use core::{fmt, str::FromStr};
+use std::{
+  borrow::ToOwned,
+  io,
+  path::Path,
+  string::{String, ToString},
+  vec,
+  vec::Vec,
+};
+
+use ipnet::IpNet;
+
+use super::{Block, RFCs, RFC};
+
+/// An error returned when loading an IANA registry export.
+#[derive(Debug)]
+pub enum IanaError {
+  /// The file could not be read.
+  Io(io::Error),
+  /// The export has no records, or lacks one of the registry columns.
+  MissingColumn(&'static str),
+  /// A value of the record, counted from 1, could not be parsed.
+  Malformed {
+    /// The number of the record.
+    record: usize,
+    /// The column of the value.
+    column: &'static str,
+  },
+}
+
+impl fmt::Display for IanaError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Io(err) => write!(f, "failed to read the registry: {err}"),
+      Self::MissingColumn(column) => write!(f, "missing registry column {column}"),
+      Self::Malformed { record, column } => {
+        write!(f, "malformed {column} in registry record {record}")
+      }
+    }
+  }
+}
+
+impl core::error::Error for IanaError {
+  fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+    match self {
+      Self::Io(err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+impl From<io::Error> for IanaError {
+  #[inline]
+  fn from(err: io::Error) -> Self {
+    Self::Io(err)
+  }
+}
+
+/// An entry loaded from an IANA Special-Purpose Address Registry export,
+/// the owned counterpart of a [`Block`].
+///
+/// Entries listing several prefixes are split into one block per prefix.
+/// Attributes the registry leaves blank or marks `N/A` are `None`, and
+/// footnote markers are dropped.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct IanaBlock {
+  net: IpNet,
+  name: String,
+  rfcs: Vec<u32>,
+  section: Option<String>,
+  allocated: String,
+  terminated: Option<String>,
+  source: Option<bool>,
+  destination: Option<bool>,
+  forwardable: Option<bool>,
+  global: Option<bool>,
+  reserved_by_protocol: Option<bool>,
+}
+
+impl IanaBlock {
+  /// Returns the address block.
+  #[inline]
+  pub const fn net(&self) -> IpNet {
+    self.net
+  }
+
+  /// Returns the descriptive name of the address block, without quotes.
+  #[inline]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the RFCs referenced by the entry, in order.
+  #[inline]
+  pub fn rfcs(&self) -> &[u32] {
+    &self.rfcs
+  }
+
+  /// Returns the section of the RFC defining the address block, if any.
+  #[inline]
+  pub fn section(&self) -> Option<&str> {
+    self.section.as_deref()
+  }
+
+  /// Returns the allocation date of the address block.
+  #[inline]
+  pub fn allocated(&self) -> &str {
+    &self.allocated
+  }
+
+  /// Returns the termination date of the address block, if any.
+  #[inline]
+  pub fn terminated(&self) -> Option<&str> {
+    self.terminated.as_deref()
+  }
+
+  /// Returns the Source attribute, see [`Block::source`].
+  #[inline]
+  pub const fn source(&self) -> Option<bool> {
+    self.source
+  }
+
+  /// Returns the Destination attribute, see [`Block::destination`].
+  #[inline]
+  pub const fn destination(&self) -> Option<bool> {
+    self.destination
+  }
+
+  /// Returns the Forwardable attribute, see [`Block::forwardable`].
+  #[inline]
+  pub const fn forwardable(&self) -> Option<bool> {
+    self.forwardable
+  }
+
+  /// Returns the Globally Reachable attribute, see [`Block::global`].
+  #[inline]
+  pub const fn global(&self) -> Option<bool> {
+    self.global
+  }
+
+  /// Returns the Reserved-by-Protocol attribute, see
+  /// [`Block::reserved_by_protocol`].
+  #[inline]
+  pub const fn reserved_by_protocol(&self) -> Option<bool> {
+    self.reserved_by_protocol
+  }
+
+  /// Returns `true` if the block describes the same entry as the shipped
+  /// one, under another prefix: the networks overlap and either the names
+  /// or the RFCs match.
+  fn is_related(&self, block: &Block) -> bool {
+    (block.net.contains(&self.net) || self.net.contains(&block.net))
+      && (block.name == self.name || self.rfcs.contains(&block.rfc))
+  }
+
+  fn mismatches(&self, block: &Block) -> impl Iterator<Item = Attribute> + '_ {
+    let block = *block;
+    Attribute::ALL
+      .into_iter()
+      .filter(move |attribute| match attribute {
+        Attribute::Name => block.name != self.name,
+        Attribute::Rfc => !self.rfcs.contains(&block.rfc),
+        Attribute::Section => block.section != self.section(),
+        Attribute::Allocated => block.allocated != self.allocated,
+        Attribute::Terminated => block.terminated != self.terminated(),
+        Attribute::Source => Some(block.source) != self.source,
+        Attribute::Destination => Some(block.destination) != self.destination,
+        Attribute::Forwardable => Some(block.forwardable) != self.forwardable,
+        Attribute::Global => block.global != self.global,
+        Attribute::ReservedByProtocol => {
+          Some(block.reserved_by_protocol) != self.reserved_by_protocol
+        }
+      })
+  }
+}
+
+/// An attribute of a registry entry, compared by [`IanaRegistry::diff`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Attribute {
+  /// The name of the block.
+  Name,
+  /// The RFC of the block, which must be one of those the registry lists.
+  Rfc,
+  /// The section of the RFC.
+  Section,
+  /// The allocation date.
+  Allocated,
+  /// The termination date.
+  Terminated,
+  /// The Source attribute.
+  Source,
+  /// The Destination attribute.
+  Destination,
+  /// The Forwardable attribute.
+  Forwardable,
+  /// The Globally Reachable attribute.
+  Global,
+  /// The Reserved-by-Protocol attribute.
+  ReservedByProtocol,
+}
+
+impl Attribute {
+  const ALL: [Self; 10] = [
+    Self::Name,
+    Self::Rfc,
+    Self::Section,
+    Self::Allocated,
+    Self::Terminated,
+    Self::Source,
+    Self::Destination,
+    Self::Forwardable,
+    Self::Global,
+    Self::ReservedByProtocol,
+  ];
+
+  /// Returns the name of the registry column.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Name => NAME,
+      Self::Rfc => SPEC,
+      Self::Section => "Section",
+      Self::Allocated => ALLOCATED,
+      Self::Terminated => TERMINATED,
+      Self::Source => SOURCE,
+      Self::Destination => DESTINATION,
+      Self::Forwardable => FORWARDABLE,
+      Self::Global => GLOBAL,
+      Self::ReservedByProtocol => RESERVED,
+    }
+  }
+
+  fn fmt_registry(&self, block: &IanaBlock, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Name => f.write_str(&block.name),
+      Self::Rfc => {
+        for (i, rfc) in block.rfcs.iter().enumerate() {
+          if i > 0 {
+            f.write_str(", ")?;
+          }
+          write!(f, "RFC {rfc}")?;
+        }
+        Ok(())
+      }
+      Self::Section => fmt_option(block.section(), f),
+      Self::Allocated => f.write_str(&block.allocated),
+      Self::Terminated => fmt_option(block.terminated(), f),
+      Self::Source => fmt_option(block.source, f),
+      Self::Destination => fmt_option(block.destination, f),
+      Self::Forwardable => fmt_option(block.forwardable, f),
+      Self::Global => fmt_option(block.global, f),
+      Self::ReservedByProtocol => fmt_option(block.reserved_by_protocol, f),
+    }
+  }
+
+  fn fmt_shipped(&self, block: &Block, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Name => f.write_str(block.name),
+      Self::Rfc => write!(f, "RFC {}", block.rfc),
+      Self::Section => fmt_option(block.section, f),
+      Self::Allocated => f.write_str(block.allocated),
+      Self::Terminated => fmt_option(block.terminated, f),
+      Self::Source => fmt::Display::fmt(&block.source, f),
+      Self::Destination => fmt::Display::fmt(&block.destination, f),
+      Self::Forwardable => fmt::Display::fmt(&block.forwardable, f),
+      Self::Global => fmt_option(block.global, f),
+      Self::ReservedByProtocol => fmt::Display::fmt(&block.reserved_by_protocol, f),
+    }
+  }
+}
+
+impl fmt::Display for Attribute {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+/// A difference between a registry export and the blocks shipped by the
+/// crate, returned by [`IanaRegistry::diff`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Difference {
+  /// The registry block is not shipped by the crate.
+  Missing(IanaBlock),
+  /// The registry block is shipped under another prefix.
+  Prefix {
+    /// The block of the registry.
+    registry: IanaBlock,
+    /// The block shipped by the crate.
+    shipped: &'static Block,
+  },
+  /// The registry block is shipped with a different attribute.
+  Mismatch {
+    /// The block of the registry.
+    registry: IanaBlock,
+    /// The block shipped by the crate.
+    shipped: &'static Block,
+    /// The differing attribute.
+    attribute: Attribute,
+  },
+  /// The shipped block is not in the registry.
+  Unlisted(&'static Block),
+}
+
+impl fmt::Display for Difference {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Missing(registry) => write!(f, "{} ({}) is missing", registry.net, registry.name),
+      Self::Prefix { registry, shipped } => write!(
+        f,
+        "{} ({}) is shipped as {}",
+        registry.net, registry.name, shipped.net
+      ),
+      Self::Mismatch {
+        registry,
+        shipped,
+        attribute,
+      } => {
+        write!(f, "{attribute} of {} differs: registry ", registry.net)?;
+        attribute.fmt_registry(registry, f)?;
+        f.write_str(", shipped ")?;
+        attribute.fmt_shipped(shipped, f)
+      }
+      Self::Unlisted(shipped) => write!(
+        f,
+        "{} ({}) is not in the registry",
+        shipped.net, shipped.name
+      ),
+    }
+  }
+}
+
+/// The blocks of an IANA [IPv4] or [IPv6] Special-Purpose Address Registry
+/// export, loaded from its CSV or XML form to audit the tables shipped by
+/// the crate against a registry snapshot.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{Attribute, Difference, IanaRegistry};
+///
+/// let csv = "\
+/// Address Block,Name,RFC,Allocation Date,Termination Date,Source,Destination,Forwardable,Globally Reachable,Reserved-by-Protocol
+/// 10.0.0.0/8,Private-Use,[RFC1918],1996-02,N/A,True,True,True,False,False
+/// 169.254.0.0/16,Link Local,[RFC3927],2005-05,N/A,True,True,False,False,True
+/// 192.0.0.0/24 [2],IETF Protocol Assignments,\"[RFC6890], Section 2.1\",2010-01,N/A,False,False,False,False,False
+/// ";
+/// let registry: IanaRegistry = csv.parse().unwrap();
+/// assert_eq!(3, registry.blocks().len());
+/// assert_eq!(Some("2.1"), registry.blocks()[2].section());
+///
+/// // the crate ships more IPv4 blocks than this excerpt
+/// let diff = registry.diff();
+/// assert!(diff.iter().all(|d| matches!(d, Difference::Unlisted(_))));
+/// ```
+///
+/// [IPv4]: https://www.iana.org/assignments/iana-ipv4-special-registry/
+/// [IPv6]: https://www.iana.org/assignments/iana-ipv6-special-registry/
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct IanaRegistry {
+  blocks: Vec<IanaBlock>,
+}
+
+impl IanaRegistry {
+  /// Loads a registry export from a local file, in CSV or XML form.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self, IanaError> {
+    std::fs::read_to_string(path)?.parse()
+  }
+
+  /// Parses the CSV form of a registry export, with its header row.
+  pub fn from_csv(csv: &str) -> Result<Self, IanaError> {
+    let mut rows = parse_csv(csv).into_iter();
+    let header = rows.next().ok_or(IanaError::MissingColumn(ADDRESS))?;
+    let mut index = [0; COLUMNS.len()];
+    for (i, column) in COLUMNS.iter().enumerate() {
+      index[i] = header
+        .iter()
+        .position(|h| h.trim().eq_ignore_ascii_case(column))
+        .ok_or(IanaError::MissingColumn(column))?;
+    }
+
+    let mut blocks = Vec::new();
+    for (record, row) in rows.enumerate() {
+      let field = |i: usize| row.get(index[i]).map_or("", String::as_str);
+      let fields = [
+        field(0),
+        field(1),
+        field(2),
+        field(3),
+        field(4),
+        field(5),
+        field(6),
+        field(7),
+        field(8),
+        field(9),
+      ];
+      push_record(&mut blocks, record + 1, fields)?;
+    }
+    Ok(Self { blocks })
+  }
+
+  /// Parses the XML form of a registry export.
+  pub fn from_xml(xml: &str) -> Result<Self, IanaError> {
+    let mut blocks = Vec::new();
+    let mut rest = xml;
+    let mut record = 0;
+    while let Some(start) = find_tag(rest, "record") {
+      let body = &rest[start..];
+      let end = body.find("</record>").ok_or(IanaError::Malformed {
+        record: record + 1,
+        column: ADDRESS,
+      })?;
+      record += 1;
+
+      let mut fields: [String; COLUMNS.len()] = Default::default();
+      for (i, tag) in XML_TAGS.iter().enumerate() {
+        fields[i] = element(&body[..end], tag)
+          .map(xml_text)
+          .ok_or(IanaError::MissingColumn(COLUMNS[i]))?;
+      }
+      push_record(&mut blocks, record, fields.each_ref().map(String::as_str))?;
+      rest = &body[end..];
+    }
+    if record == 0 {
+      return Err(IanaError::MissingColumn(ADDRESS));
+    }
+    Ok(Self { blocks })
+  }
+
+  /// Returns the loaded blocks, in registry order.
+  #[inline]
+  pub fn blocks(&self) -> &[IanaBlock] {
+    &self.blocks
+  }
+
+  /// Compares the registry with the blocks shipped by the crate.
+  ///
+  /// A registry block without a shipped block of the same prefix is
+  /// reported as [`Prefix`](Difference::Prefix) if a shipped block overlaps
+  /// it under the same name or RFC, and as [`Missing`](Difference::Missing)
+  /// otherwise. Shipped blocks of the address families of the registry that
+  /// match no registry block are reported as
+  /// [`Unlisted`](Difference::Unlisted).
+  pub fn diff(&self) -> Vec<Difference> {
+    let mut shipped: Vec<&'static Block> = Vec::new();
+    for block in RFCs::iter().flat_map(RFC::blocks) {
+      if shipped.iter().all(|b| b.net != block.net) {
+        shipped.push(block);
+      }
+    }
+
+    let mut matched = vec![false; shipped.len()];
+    let mut diff = Vec::new();
+    for registry in &self.blocks {
+      if let Some(i) = shipped.iter().position(|b| b.net == registry.net) {
+        matched[i] = true;
+        diff.extend(
+          registry
+            .mismatches(shipped[i])
+            .map(|attribute| Difference::Mismatch {
+              registry: registry.clone(),
+              shipped: shipped[i],
+              attribute,
+            }),
+        );
+      } else if let Some(i) = shipped.iter().position(|b| registry.is_related(b)) {
+        matched[i] = true;
+        diff.push(Difference::Prefix {
+          registry: registry.clone(),
+          shipped: shipped[i],
+        });
+      } else {
+        diff.push(Difference::Missing(registry.clone()));
+      }
+    }
+
+    let family = |net: &IpNet| matches!(net, IpNet::V4(_));
+    diff.extend(
+      shipped
+        .into_iter()
+        .zip(matched)
+        .filter(|(block, matched)| {
+          !matched
+            && self
+              .blocks
+              .iter()
+              .any(|b| family(&b.net) == family(&block.net))
+        })
+        .map(|(block, _)| Difference::Unlisted(block)),
+    );
+    diff
+  }
+}
+
+impl FromStr for IanaRegistry {
+  type Err = IanaError;
+
+  /// Parses a registry export, as XML if it starts with `<` and as CSV
+  /// otherwise.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if s.trim_start().starts_with('<') {
+      Self::from_xml(s)
+    } else {
+      Self::from_csv(s)
+    }
+  }
+}
+
+const ADDRESS: &str = "Address Block";
+const NAME: &str = "Name";
+const SPEC: &str = "RFC";
+const ALLOCATED: &str = "Allocation Date";
+const TERMINATED: &str = "Termination Date";
+const SOURCE: &str = "Source";
+const DESTINATION: &str = "Destination";
+const FORWARDABLE: &str = "Forwardable";
+const GLOBAL: &str = "Globally Reachable";
+const RESERVED: &str = "Reserved-by-Protocol";
+
+/// The registry columns, in the order of the CSV export.
+const COLUMNS: [&str; 10] = [
+  ADDRESS,
+  NAME,
+  SPEC,
+  ALLOCATED,
+  TERMINATED,
+  SOURCE,
+  DESTINATION,
+  FORWARDABLE,
+  GLOBAL,
+  RESERVED,
+];
+
+/// The elements of a `<record>` of the XML export, in the order of
+/// [`COLUMNS`].
+const XML_TAGS: [&str; 10] = [
+  "address",
+  "name",
+  "spec",
+  "allocation",
+  "termination",
+  "source",
+  "destination",
+  "forwardable",
+  "global",
+  "reserved",
+];
+
+fn push_record(
+  blocks: &mut Vec<IanaBlock>,
+  record: usize,
+  fields: [&str; COLUMNS.len()],
+) -> Result<(), IanaError> {
+  if fields.iter().all(|field| field.trim().is_empty()) {
+    return Ok(());
+  }
+  let malformed = |column| IanaError::Malformed { record, column };
+  let flag = |i: usize| match strip_notes(fields[i]).as_str() {
+    "" => Ok(None),
+    flag if flag.eq_ignore_ascii_case("n/a") => Ok(None),
+    flag if flag.eq_ignore_ascii_case("true") => Ok(Some(true)),
+    flag if flag.eq_ignore_ascii_case("false") => Ok(Some(false)),
+    _ => Err(malformed(COLUMNS[i])),
+  };
+
+  let nets = strip_notes(fields[0])
+    .split(',')
+    .map(|net| net.trim().parse::<IpNet>())
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|_| malformed(ADDRESS))?;
+  let (rfcs, section) = parse_spec(fields[2]);
+  let terminated = strip_notes(fields[4]);
+  let terminated =
+    (!terminated.is_empty() && !terminated.eq_ignore_ascii_case("n/a")).then_some(terminated);
+  let (source, destination, forwardable) = (flag(5)?, flag(6)?, flag(7)?);
+  let (global, reserved_by_protocol) = (flag(8)?, flag(9)?);
+
+  for net in nets {
+    blocks.push(IanaBlock {
+      net,
+      name: strip_notes(fields[1]).trim_matches('"').trim().to_owned(),
+      rfcs: rfcs.clone(),
+      section: section.clone(),
+      allocated: strip_notes(fields[3]),
+      terminated: terminated.clone(),
+      source,
+      destination,
+      forwardable,
+      global,
+      reserved_by_protocol,
+    });
+  }
+  Ok(())
+}
+
+/// Removes footnote markers such as `[2]` and surrounding whitespace.
+fn strip_notes(s: &str) -> String {
+  let mut out = String::with_capacity(s.len());
+  let mut rest = s;
+  while let Some(start) = rest.find('[') {
+    let (before, after) = rest.split_at(start);
+    out.push_str(before);
+    match after.find(']') {
+      Some(end) if end > 1 && after[1..end].bytes().all(|b| b.is_ascii_digit()) => {
+        rest = &after[end + 1..];
+      }
+      _ => {
+        out.push('[');
+        rest = &after[1..];
+      }
+    }
+  }
+  out.push_str(rest);
+  out.trim().to_owned()
+}
+
+/// Parses a reference such as `[RFC8190][RFC919], Section 7` into its RFC
+/// numbers and section.
+fn parse_spec(s: &str) -> (Vec<u32>, Option<String>) {
+  let mut rfcs = Vec::new();
+  let mut rest = s;
+  while let Some(start) = rest.find('[') {
+    rest = &rest[start + 1..];
+    let end = rest.find(']').unwrap_or(rest.len());
+    let reference = &rest[..end];
+    if let Some(rfc) = reference
+      .get(..3)
+      .filter(|prefix| prefix.eq_ignore_ascii_case("rfc"))
+      .and_then(|_| reference[3..].trim().parse().ok())
+    {
+      rfcs.push(rfc);
+    }
+    rest = &rest[end..];
+  }
+
+  let section = s.find("Section").map(|i| {
+    s[i + "Section".len()..]
+      .trim()
+      .trim_end_matches(['.', ','])
+      .to_string()
+  });
+  (rfcs, section.filter(|section| !section.is_empty()))
+}
+
+/// Splits CSV text into rows of fields, handling quoted fields.
+fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+  let mut rows = Vec::new();
+  let mut row = Vec::new();
+  let mut field = String::new();
+  let mut quoted = false;
+  let mut chars = csv.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '"' if quoted && chars.peek() == Some(&'"') => {
+        chars.next();
+        field.push('"');
+      }
+      '"' => quoted = !quoted,
+      ',' if !quoted => row.push(core::mem::take(&mut field)),
+      '\r' if !quoted => {}
+      '\n' if !quoted => {
+        row.push(core::mem::take(&mut field));
+        rows.push(core::mem::take(&mut row));
+      }
+      c => field.push(c),
+    }
+  }
+  if !field.is_empty() || !row.is_empty() {
+    row.push(field);
+    rows.push(row);
+  }
+  rows
+}
+
+/// Returns the offset of the first `<tag>` or `<tag ...>` element.
+fn find_tag(xml: &str, tag: &str) -> Option<usize> {
+  let mut offset = 0;
+  while let Some(start) = xml[offset..].find('<') {
+    let start = offset + start;
+    let rest = &xml[start + 1..];
+    if rest.starts_with(tag)
+      && matches!(
+        rest[tag.len()..].chars().next(),
+        Some('>' | ' ' | '\t' | '\r' | '\n' | '/')
+      )
+    {
+      return Some(start);
+    }
+    offset = start + 1;
+  }
+  None
+}
+
+/// Returns the raw content of the first `<tag>` element.
+fn element<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+  let start = find_tag(xml, tag)?;
+  let open = start + xml[start..].find('>')?;
+  if xml[..open].ends_with('/') {
+    return Some("");
+  }
+  let content = &xml[open + 1..];
+  let close = std::format!("</{tag}>");
+  content.find(close.as_str()).map(|end| &content[..end])
+}
+
+/// Returns the text of XML content, rendering RFC cross-references as
+/// `[RFCnnnn]` and dropping other markup.
+fn xml_text(xml: &str) -> String {
+  let mut out = String::with_capacity(xml.len());
+  let mut rest = xml;
+  while let Some(start) = rest.find('<') {
+    out.push_str(&rest[..start]);
+    let end = rest[start..]
+      .find('>')
+      .map_or(rest.len(), |end| start + end + 1);
+    let tag = &rest[start..end];
+    if tag.starts_with("<xref") && tag.contains("type=\"rfc\"") {
+      if let Some(data) = tag
+        .split("data=\"")
+        .nth(1)
+        .and_then(|d| d.split('"').next())
+      {
+        out.push('[');
+        out.push_str(&data.to_ascii_uppercase());
+        out.push(']');
+      }
+    }
+    rest = &rest[end..];
+  }
+  out.push_str(rest);
+  out
+    .replace("&quot;", "\"")
+    .replace("&apos;", "'")
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&amp;", "&")
+}
+
+fn fmt_option<T: fmt::Display>(value: Option<T>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+  match value {
+    Some(value) => value.fmt(f),
+    None => f.write_str("N/A"),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  /// Made-up rows exercising the parser and every kind of difference.
+  const CSV: &str = "\
+Address Block,Name,RFC,Allocation Date,Termination Date,Source,Destination,Forwardable,Globally Reachable,Reserved-by-Protocol\r
+10.0.0.0/8,\"\"\"Example Private\"\"\",\"[RFC1918], Section 3\",1996-02,N/A,True,True,True,False,True\r
+\"192.0.0.170/32, 192.0.0.171/32\",NAT64/DNS64 Discovery,\"[RFC9999][RFC8880], Section 2.2\",2013-02,N/A,False,False,False,False,True\r
+192.0.0.0/25,IETF Protocol Assignments,[RFC6890],2010-01,N/A,False,False,False,False,False\r
+198.51.100.0/24,Documentation (TEST-NET-2),[RFC5737],2010-01,2030-01,,,,,\r
+203.0.113.0/24,Documentation (TEST-NET-3),[RFC9999],2010-01,N/A,False,False,False,False,False\r
+44.0.0.0/8 [1],Example Missing,[RFC9999],2099-01,N/A,True,True,True,True [2],False\r
+";
+
+  /// Rows of the IANA IPv4 registry. The deprecated 6to4 relay anycast
+  /// entries are left out: the crate still ships `192.88.99.0/24` with its
+  /// RFC 3068 attributes and lacks `192.88.99.2/32`.
+  const REGISTRY_V4: &str = r#"Address Block,Name,RFC,Allocation Date,Termination Date,Source,Destination,Forwardable,Globally Reachable,Reserved-by-Protocol
+0.0.0.0/8,"""This network""","[RFC791], Section 3.2",1981-09,N/A,True,False,False,False,True
+0.0.0.0/32,"""This host on this network""","[RFC1122], Section 3.2.1.3",1981-09,N/A,True,False,False,False,True
+10.0.0.0/8,Private-Use,[RFC1918],1996-02,N/A,True,True,True,False,False
+100.64.0.0/10,Shared Address Space,[RFC6598],2012-04,N/A,True,True,True,False,False
+127.0.0.0/8,Loopback,"[RFC1122], Section 3.2.1.3",1981-09,N/A,False [1],False [1],False [1],False [1],True
+169.254.0.0/16,Link Local,[RFC3927],2005-05,N/A,True,True,False,False,True
+172.16.0.0/12,Private-Use,[RFC1918],1996-02,N/A,True,True,True,False,False
+192.0.0.0/24 [2],IETF Protocol Assignments,"[RFC6890], Section 2.1",2010-01,N/A,False,False,False,False,False
+192.0.0.0/29,IPv4 Service Continuity Prefix,[RFC7335],2011-06,N/A,True,True,True,False,False
+192.0.0.8/32,IPv4 dummy address,[RFC7600],2015-03,N/A,True,False,False,False,False
+192.0.0.9/32,Port Control Protocol Anycast,[RFC7723],2015-10,N/A,True,True,True,True,False
+192.0.0.10/32,Traversal Using Relays around NAT Anycast,[RFC8155],2017-02,N/A,True,True,True,True,False
+"192.0.0.170/32, 192.0.0.171/32",NAT64/DNS64 Discovery,"[RFC8880][RFC7050], Section 2.2",2013-02,N/A,False,False,False,False,True
+192.0.2.0/24,Documentation (TEST-NET-1),[RFC5737],2010-01,N/A,False,False,False,False,False
+192.31.196.0/24,AS112-v4,[RFC7535],2014-12,N/A,True,True,True,True,False
+192.52.193.0/24,AMT,[RFC7450],2014-12,N/A,True,True,True,True,False
+192.168.0.0/16,Private-Use,[RFC1918],1996-02,N/A,True,True,True,False,False
+192.175.48.0/24,Direct Delegation AS112 Service,[RFC7534],1996-01,N/A,True,True,True,True,False
+198.18.0.0/15,Benchmarking,[RFC2544],1999-03,N/A,True,True,True,False,False
+198.51.100.0/24,Documentation (TEST-NET-2),[RFC5737],2010-01,N/A,False,False,False,False,False
+203.0.113.0/24,Documentation (TEST-NET-3),[RFC5737],2010-01,N/A,False,False,False,False,False
+240.0.0.0/4,Reserved,"[RFC1112], Section 4",1989-08,N/A,False,False,False,False,True
+255.255.255.255/32,Limited Broadcast,"[RFC8190][RFC919], Section 7",1984-10,N/A,False,True,False,False [3],True
+"#;
+
+  /// Rows of the IANA IPv6 registry. The deprecated ORCHID entry is left
+  /// out, as the crate ships it with its RFC 4843 attributes, and so is
+  /// `3fff::/20` until its allocation date is checked against a snapshot.
+  const REGISTRY_V6: &str = r#"Address Block,Name,RFC,Allocation Date,Termination Date,Source,Destination,Forwardable,Globally Reachable,Reserved-by-Protocol
+::1/128,Loopback Address,[RFC4291],2006-02,N/A,False,False,False,False,True
+::/128,Unspecified Address,[RFC4291],2006-02,N/A,True,False,False,False,True
+::ffff:0:0/96,IPv4-mapped Address,[RFC4291],2006-02,N/A,False,False,False,False,True
+64:ff9b::/96,IPv4-IPv6 Translat.,[RFC6052],2010-10,N/A,True,True,True,True,False
+64:ff9b:1::/48,IPv4-IPv6 Translat.,[RFC8215],2017-06,N/A,True,True,True,False,False
+100::/64,Discard-Only Address Block,[RFC6666],2012-06,N/A,True,True,True,False,False
+2001::/23,IETF Protocol Assignments,[RFC2928],2000-09,N/A,False [1],False [1],False [1],False [1],False
+2001::/32,TEREDO,"[RFC4380][RFC8190]",2006-01,N/A,True,True,True,N/A [2],False
+2001:1::1/128,Port Control Protocol Anycast,[RFC7723],2015-10,N/A,True,True,True,True,False
+2001:1::2/128,Traversal Using Relays around NAT Anycast,[RFC8155],2017-02,N/A,True,True,True,True,False
+2001:2::/48,Benchmarking,[RFC5180][RFC Errata 1752],2008-04,N/A,True,True,True,False,False
+2001:3::/32,AMT,[RFC7450],2014-12,N/A,True,True,True,True,False
+2001:4:112::/48,AS112-v6,[RFC7535],2014-12,N/A,True,True,True,True,False
+2001:20::/28,ORCHIDv2,[RFC7343],2014-07,N/A,True,True,True,True,False
+2001:30::/28,Drone Remote ID Protocol Entity Tags (DETs) Prefix,[RFC9374],2022-12,N/A,True,True,True,True,False
+2001:db8::/32,Documentation,[RFC3849],2004-07,N/A,False,False,False,False,False
+2002::/16,6to4,[RFC3056],2001-02,N/A,True,True,True,N/A [3],False
+2620:4f:8000::/48,Direct Delegation AS112 Service,[RFC7534],2011-05,N/A,True,True,True,True,False
+5f00::/16,Segment Routing (SRv6) SIDs,[RFC9602],2024-04,N/A,True,True,True,False,False
+fc00::/7,Unique-Local,"[RFC4193][RFC8190]",2005-10,N/A,True,True,True,False [4],False
+fe80::/10,Link-Local Unicast,[RFC4291],2006-02,N/A,True,True,False,False,True
+"#;
+
+  const XML: &str = r#"<?xml version='1.0' encoding='UTF-8'?>
+<registry xmlns="http://www.iana.org/assignments" id="iana-ipv6-special-registry">
+  <registry id="iana-ipv6-special-registry-1">
+    <record>
+      <address>::1/128</address>
+      <name>Loopback Address</name>
+      <spec><xref type="rfc" data="rfc4291"/></spec>
+      <allocation>2006-02</allocation>
+      <termination>N/A</termination>
+      <source>False</source>
+      <destination>False</destination>
+      <forwardable>False</forwardable>
+      <global>False</global>
+      <reserved>True</reserved>
+    </record>
+    <record>
+      <address>2001::/23</address>
+      <name>IETF Protocol Assignments</name>
+      <spec><xref type="rfc" data="rfc2928"/></spec>
+      <allocation>2000-09</allocation>
+      <termination>N/A</termination>
+      <source>False<xref type="note" data="1"/></source>
+      <destination>False<xref type="note" data="1"/></destination>
+      <forwardable>False<xref type="note" data="1"/></forwardable>
+      <global>False<xref type="note" data="1"/></global>
+      <reserved>False</reserved>
+    </record>
+    <record>
+      <address>2002::/16<xref type="note" data="2"/></address>
+      <name>6to4</name>
+      <spec><xref type="rfc" data="rfc3056"/></spec>
+      <allocation>2001-02</allocation>
+      <termination>N/A</termination>
+      <source>True</source>
+      <destination>True</destination>
+      <forwardable>True</forwardable>
+      <global>N/A<xref type="note" data="2"/></global>
+      <reserved>False</reserved>
+    </record>
+  </registry>
+</registry>
+"#;
+
+  fn mismatches(diff: &[Difference], net: &str) -> Vec<Attribute> {
+    let net: IpNet = net.parse().unwrap();
+    diff
+      .iter()
+      .filter_map(|d| match d {
+        Difference::Mismatch {
+          registry,
+          attribute,
+          ..
+        } if registry.net() == net => Some(*attribute),
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn csv() {
+    let registry = IanaRegistry::from_csv(CSV).unwrap();
+    let blocks = registry.blocks();
+    assert_eq!(blocks.len(), 7);
+
+    assert_eq!(blocks[0].name(), "Example Private");
+    assert_eq!(blocks[0].rfcs(), &[1918]);
+    assert_eq!(blocks[0].section(), Some("3"));
+    assert_eq!(blocks[1].net(), "192.0.0.170/32".parse().unwrap());
+    assert_eq!(blocks[2].net(), "192.0.0.171/32".parse().unwrap());
+    assert_eq!(blocks[2].rfcs(), &[9999, 8880]);
+    assert_eq!(blocks[4].terminated(), Some("2030-01"));
+    assert_eq!(blocks[4].source(), None);
+    assert_eq!(blocks[6].net(), "44.0.0.0/8".parse().unwrap());
+    assert_eq!(blocks[6].global(), Some(true));
+
+    let diff = registry.diff();
+    assert_eq!(
+      mismatches(&diff, "10.0.0.0/8"),
+      [
+        Attribute::Name,
+        Attribute::Section,
+        Attribute::ReservedByProtocol
+      ]
+    );
+    assert!(mismatches(&diff, "192.0.0.170/32").is_empty());
+    assert_eq!(
+      mismatches(&diff, "198.51.100.0/24"),
+      [
+        Attribute::Terminated,
+        Attribute::Source,
+        Attribute::Destination,
+        Attribute::Forwardable,
+        Attribute::Global,
+        Attribute::ReservedByProtocol
+      ]
+    );
+    assert_eq!(mismatches(&diff, "203.0.113.0/24"), [Attribute::Rfc]);
+    assert!(!diff.iter().any(
+      |d| matches!(d, Difference::Unlisted(shipped) if matches!(shipped.net(), IpNet::V6(_)))
+    ));
+
+    let messages: Vec<String> = diff.iter().map(ToString::to_string).collect();
+    for message in [
+      "Name of 10.0.0.0/8 differs: registry Example Private, shipped Private-Use",
+      "Reserved-by-Protocol of 10.0.0.0/8 differs: registry true, shipped false",
+      "192.0.0.0/25 (IETF Protocol Assignments) is shipped as 192.0.0.0/24",
+      "Termination Date of 198.51.100.0/24 differs: registry 2030-01, shipped N/A",
+      "Source of 198.51.100.0/24 differs: registry N/A, shipped false",
+      "RFC of 203.0.113.0/24 differs: registry RFC 9999, shipped RFC 5737",
+      "44.0.0.0/8 (Example Missing) is missing",
+      "172.16.0.0/12 (Private-Use) is not in the registry",
+    ] {
+      assert!(messages.iter().any(|m| m == message), "{message}");
+    }
+  }
+
+  #[test]
+  fn registry_matches_shipped() {
+    let left_out: Vec<IpNet> = ["192.88.99.0/24", "2001:10::/28", "3fff::/20"]
+      .iter()
+      .map(|net| net.parse().unwrap())
+      .collect();
+    for csv in [REGISTRY_V4, REGISTRY_V6] {
+      let diff = IanaRegistry::from_csv(csv).unwrap().diff();
+      let unexpected: Vec<String> = diff
+        .iter()
+        .filter(
+          |d| !matches!(d, Difference::Unlisted(shipped) if left_out.contains(&shipped.net())),
+        )
+        .map(ToString::to_string)
+        .collect();
+      assert!(unexpected.is_empty(), "{unexpected:#?}");
+    }
+  }
+
+  #[test]
+  fn xml() {
+    let registry: IanaRegistry = XML.parse().unwrap();
+    let blocks = registry.blocks();
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(blocks[1].rfcs(), &[2928]);
+    assert_eq!(blocks[1].source(), Some(false));
+    assert_eq!(blocks[2].net(), "2002::/16".parse().unwrap());
+    assert_eq!(blocks[2].global(), None);
+
+    let diff = registry.diff();
+    assert!(mismatches(&diff, "2001::/23").is_empty());
+    assert!(mismatches(&diff, "2002::/16").is_empty());
+    assert!(mismatches(&diff, "::1/128").is_empty());
+    assert!(diff.iter().all(|d| match d {
+      Difference::Unlisted(shipped) => matches!(shipped.net(), IpNet::V6(_)),
+      _ => true,
+    }));
+  }
+
+  #[test]
+  fn errors() {
+    assert!(matches!(
+      IanaRegistry::from_csv("Address Block,Name\n"),
+      Err(IanaError::MissingColumn(SPEC))
+    ));
+    let bad = CSV.replace("2010-01,2030-01,,", "2010-01,2030-01,Yes,");
+    assert!(matches!(
+      IanaRegistry::from_csv(&bad),
+      Err(IanaError::Malformed {
+        record: 4,
+        column: SOURCE
+      })
+    ));
+    assert!(matches!(
+      IanaRegistry::from_xml("<registry></registry>"),
+      Err(IanaError::MissingColumn(ADDRESS))
+    ));
+    assert!(matches!(
+      IanaRegistry::load("/nonexistent/iana-ipv4-special-registry.csv"),
+      Err(IanaError::Io(_))
+    ));
+  }
+}
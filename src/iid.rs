@@ -0,0 +1,274 @@
+use core::{
+  fmt,
+  net::{Ipv4Addr, Ipv6Addr},
+};
+
+use super::{embedded::is_isatap_iid, is_eui64_ipv6_addr};
+
+/// A pattern of IPv6 interface identifiers, making addresses easier to
+/// guess when scanning a network
+/// ([RFC 7707 §4.1](https://datatracker.ietf.org/doc/html/rfc7707#section-4.1)).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum IidPattern {
+  /// All zeros except for the low-order bytes, e.g. `2001:db8::1` or
+  /// `2001:db8::1:2`.
+  LowByte,
+  /// An IPv4 address in the low 32 bits, e.g. `2001:db8::c000:201`, or one
+  /// octet per 16-bit word written in decimal, e.g. `2001:db8::192:0:2:1`.
+  EmbeddedIpv4(Ipv4Addr),
+  /// A well-known service port written in decimal, alone or next to a low
+  /// byte, e.g. `2001:db8::80` or `2001:db8::1:443`.
+  EmbeddedPort(u16),
+  /// Hex-speak words, e.g. `2001:db8::dead:beef` or `2001:db8::cafe`.
+  Wordy,
+  /// A modified EUI-64 identifier derived from a MAC address, with `ff:fe`
+  /// in its middle octets.
+  Eui64,
+  /// An ISATAP identifier, `0:5efe:a.b.c.d` or `200:5efe:a.b.c.d`.
+  Isatap,
+  /// No visible structure, as with temporary or stable opaque identifiers.
+  Randomized,
+}
+
+impl IidPattern {
+  /// Returns `true` unless the identifier looks randomized.
+  #[inline]
+  pub const fn is_guessable(&self) -> bool {
+    !matches!(self, Self::Randomized)
+  }
+
+  /// Returns the name of the pattern.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::LowByte => "low-byte",
+      Self::EmbeddedIpv4(_) => "embedded IPv4",
+      Self::EmbeddedPort(_) => "embedded port",
+      Self::Wordy => "wordy",
+      Self::Eui64 => "EUI-64",
+      Self::Isatap => "ISATAP",
+      Self::Randomized => "randomized",
+    }
+  }
+}
+
+impl fmt::Display for IidPattern {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::EmbeddedIpv4(ip) => write!(f, "{} {ip}", self.name()),
+      Self::EmbeddedPort(port) => write!(f, "{} {port}", self.name()),
+      _ => f.write_str(self.name()),
+    }
+  }
+}
+
+/// Returns the pattern of the interface identifier, the low 64 bits of the
+/// IPv6 address, or `None` if it has no recognised pattern.
+///
+/// The patterns are checked in this order: ISATAP, EUI-64, embedded port,
+/// low-byte, wordy, embedded IPv4 and randomized. An identifier is
+/// randomized-looking when none of its 16-bit words is zero and it has
+/// between 20 and 44 bits set.
+///
+/// Recognising an IPv4 address in the low 32 bits is a heuristic: its first
+/// octet must be a unicast one other than loopback, and the two words must
+/// not both be made of decimal digits, as `::1234:5678` is more likely a
+/// counter than `18.52.86.120`.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{iid_pattern, IidPattern};
+/// use std::net::Ipv4Addr;
+///
+/// assert_eq!(Some(IidPattern::LowByte), iid_pattern("2001:db8::1".parse().unwrap()));
+/// assert_eq!(Some(IidPattern::EmbeddedPort(443)), iid_pattern("2001:db8::443".parse().unwrap()));
+/// assert_eq!(
+///   Some(IidPattern::EmbeddedIpv4(Ipv4Addr::new(192, 0, 2, 1))),
+///   iid_pattern("2001:db8::192:0:2:1".parse().unwrap())
+/// );
+/// assert_eq!(Some(IidPattern::Wordy), iid_pattern("2001:db8::dead:beef".parse().unwrap()));
+/// assert_eq!(
+///   Some(IidPattern::Randomized),
+///   iid_pattern("2001:db8::3a1c:9b47:e02d:5f86".parse().unwrap())
+/// );
+/// ```
+pub const fn iid_pattern(ip: Ipv6Addr) -> Option<IidPattern> {
+  let iid = ip.to_bits() as u64;
+  let [_, _, _, _, a, b, c, d] = ip.segments();
+
+  if is_isatap_iid(ip) {
+    return Some(IidPattern::Isatap);
+  }
+  if is_eui64_ipv6_addr(ip) {
+    return Some(IidPattern::Eui64);
+  }
+  if a == 0 && b == 0 {
+    if let Some(port) = service_port(d) {
+      if c <= 0xff {
+        return Some(IidPattern::EmbeddedPort(port));
+      }
+    }
+    if let Some(port) = service_port(c) {
+      if d <= 0xff {
+        return Some(IidPattern::EmbeddedPort(port));
+      }
+    }
+    if c <= 0xff && d <= 0xff {
+      return Some(IidPattern::LowByte);
+    }
+  }
+  if is_wordy([a, b, c, d]) {
+    return Some(IidPattern::Wordy);
+  }
+  if a == 0 && b == 0 && is_plausible_ipv4(c, d) {
+    return Some(IidPattern::EmbeddedIpv4(Ipv4Addr::from_bits(iid as u32)));
+  }
+  if let (Some(a), Some(b), Some(c), Some(d)) = (octet(a), octet(b), octet(c), octet(d)) {
+    if a != 0 {
+      return Some(IidPattern::EmbeddedIpv4(Ipv4Addr::new(a, b, c, d)));
+    }
+  }
+  if a != 0 && b != 0 && c != 0 && d != 0 && matches!(iid.count_ones(), 20..=44) {
+    return Some(IidPattern::Randomized);
+  }
+  None
+}
+
+/// Well-known service ports, as found in server addresses.
+const PORTS: &[u16] = &[
+  21, 22, 23, 25, 53, 80, 110, 123, 143, 389, 443, 465, 587, 636, 993, 995, 1433, 3306, 5432, 8080,
+];
+
+/// Hex-speak words.
+const WORDS: &[u16] = &[
+  0xabba, 0xace, 0xadd, 0xbad, 0xbabe, 0xbead, 0xbeef, 0xc0de, 0xcafe, 0xdad, 0xdead, 0xdeaf,
+  0xdeed, 0xd00d, 0xf00d, 0xface, 0xfade, 0xfee, 0xfeed,
+];
+
+/// Reads a 16-bit word written in decimal, e.g. `0x443` as `443`.
+const fn decimal(word: u16) -> Option<u16> {
+  let mut value = 0;
+  let mut shift = 16;
+  while shift > 0 {
+    shift -= 4;
+    let digit = (word >> shift) & 0xf;
+    if digit > 9 {
+      return None;
+    }
+    value = value * 10 + digit;
+  }
+  Some(value)
+}
+
+const fn octet(word: u16) -> Option<u8> {
+  match decimal(word) {
+    Some(value) if value <= 255 => Some(value as u8),
+    _ => None,
+  }
+}
+
+const fn service_port(word: u16) -> Option<u16> {
+  let port = match decimal(word) {
+    Some(port) => port,
+    None => return None,
+  };
+  let mut i = 0;
+  while i < PORTS.len() {
+    if PORTS[i] == port {
+      return Some(port);
+    }
+    i += 1;
+  }
+  None
+}
+
+/// Returns `true` if the low 32 bits of an identifier, `high:low`, look
+/// like an IPv4 address written in hexadecimal.
+const fn is_plausible_ipv4(high: u16, low: u16) -> bool {
+  let first = high >> 8;
+  first != 0 && first < 224 && first != 127 && (decimal(high).is_none() || decimal(low).is_none())
+}
+
+/// Returns `true` if every non-zero word is hex-speak, and at least one is.
+const fn is_wordy(words: [u16; 4]) -> bool {
+  let mut found = false;
+  let mut i = 0;
+  while i < words.len() {
+    if words[i] != 0 {
+      let mut j = 0;
+      while j < WORDS.len() && WORDS[j] != words[i] {
+        j += 1;
+      }
+      if j == WORDS.len() {
+        return false;
+      }
+      found = true;
+    }
+    i += 1;
+  }
+  found
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  #[test]
+  fn patterns() {
+    for (ip, pattern) in [
+      ("2001:db8::1", Some(IidPattern::LowByte)),
+      ("2001:db8::1:2", Some(IidPattern::LowByte)),
+      ("2001:db8::", Some(IidPattern::LowByte)),
+      ("2001:db8::80", Some(IidPattern::EmbeddedPort(80))),
+      ("2001:db8::1:25", Some(IidPattern::EmbeddedPort(25))),
+      ("2001:db8::8080:1", Some(IidPattern::EmbeddedPort(8080))),
+      (
+        "2001:db8::c000:201",
+        Some(IidPattern::EmbeddedIpv4(Ipv4Addr::new(192, 0, 2, 1))),
+      ),
+      (
+        "2001:db8::10:0:0:1",
+        Some(IidPattern::EmbeddedIpv4(Ipv4Addr::new(10, 0, 0, 1))),
+      ),
+      ("2001:db8::256:0:0:1", None),
+      ("2001:db8::1234:5678", None),
+      ("2001:db8::f000:1", None),
+      ("2001:db8::7f00:1", None),
+      (
+        "2001:db8::a00:1",
+        Some(IidPattern::EmbeddedIpv4(Ipv4Addr::new(10, 0, 0, 1))),
+      ),
+      ("2001:db8::cafe", Some(IidPattern::Wordy)),
+      ("2001:db8::bad:c0de", Some(IidPattern::Wordy)),
+      ("2001:db8::dead:beef:1", None),
+      ("fe80::2aa:ff:fe28:9c5a", Some(IidPattern::Eui64)),
+      ("fe80::5efe:c000:201", Some(IidPattern::Isatap)),
+      ("fe80::200:5efe:c000:201", Some(IidPattern::Isatap)),
+      ("2002:c000:201::5efe:a00:1", Some(IidPattern::Isatap)),
+      ("2001:db8::1234:0:5678", None),
+      ("2001:db8::ffff:ffff:ffff:fff0", None),
+      (
+        "2001:db8::a4c2:7e19:3bd0:61f5",
+        Some(IidPattern::Randomized),
+      ),
+    ] {
+      let ip: Ipv6Addr = ip.parse().unwrap();
+      assert_eq!(iid_pattern(ip), pattern, "{ip}");
+      if let Some(pattern) = pattern {
+        assert_eq!(pattern.is_guessable(), pattern != IidPattern::Randomized);
+      }
+    }
+
+    assert_eq!(
+      IidPattern::EmbeddedPort(443).to_string(),
+      "embedded port 443"
+    );
+    assert_eq!(
+      IidPattern::EmbeddedIpv4(Ipv4Addr::new(10, 0, 0, 1)).to_string(),
+      "embedded IPv4 10.0.0.1"
+    );
+  }
+}
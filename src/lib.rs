@@ -5,18 +5,67 @@
 #![cfg_attr(docsrs, allow(unused_attributes))]
 #![deny(missing_docs)]
 
-#[cfg(test)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(any(test, feature = "iana"))]
 extern crate std;
 
 use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 pub use ipnet::{IpNet, Ipv4Net, Ipv6Net};
 
+pub use address_selection::{PolicyEntry, PolicyTable, SourceCandidate};
+pub use anycast::{subnet_anycast, SubnetAnycast};
+pub use block::Block;
+pub use class::{classify, AddressClass, AddressScope};
+pub use embedded::{embedded_ipv4, Embedding};
 pub use forwarding_black_list::{FORWARDING_BLACKLIST, FORWARDING_BLACKLIST_ID};
+#[cfg(feature = "iana")]
+#[cfg_attr(docsrs, doc(cfg(feature = "iana")))]
+pub use iana::{Attribute, Difference, IanaBlock, IanaError, IanaRegistry};
+pub use iid::{iid_pattern, IidPattern};
+pub use mac::{MacAddr, ParseMacAddrError};
+pub use multicast::{Ipv4Multicast, Ipv4MulticastBlock, Ipv6Multicast, MulticastScope};
+pub use multicast_groups::{MulticastGroup, MulticastGroups};
+pub use nat64::{Nat64Error, Nat64Prefix};
+pub use parse::ParseRfcError;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use policy::{DestinationPolicy, Reason, Rule, Verdict};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use registry::{Classification, Classify, Entry, Registry};
 pub use semantic::*;
-
+pub use stable_iid::{is_reserved_iid, Prf, Sha1Prf, StableIid};
+pub use teredo::TeredoAddr;
+pub use trie::{Lookup, Match, PrefixTrie};
+pub use ula::UlaPrefix;
+
+mod address_selection;
+mod anycast;
+mod block;
+mod class;
+mod embedded;
 mod forwarding_black_list;
+#[cfg(feature = "iana")]
+mod iana;
+mod iid;
+mod mac;
+mod multicast;
+mod multicast_groups;
+mod nat64;
+mod parse;
+#[cfg(feature = "alloc")]
+mod policy;
+#[cfg(feature = "alloc")]
+mod registry;
 mod semantic;
+mod sha1;
+mod stable_iid;
+mod teredo;
+mod trie;
+mod ula;
 
 macro_rules! rfcs {
   ($(($index:literal, $id:literal)), +$(,)?) => {
@@ -31,7 +80,7 @@ macro_rules! rfcs {
 
       bitflags::bitflags! {
         /// Address-related RFC flags
-        #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
         pub struct Filter: u128 {
           $(
@@ -62,25 +111,25 @@ macro_rules! rfcs {
         }
       }
 
-      impl ::core::ops::Index<&str> for RFCs {
-        type Output = RFC;
-
-        fn index(&self, index: &str) -> &Self::Output {
-          let s = if index.starts_with("RFC") {
-            index.trim_start_matches("RFC").trim()
-          } else if index.starts_with("rfc") {
-            index.trim_start_matches("rfc").trim()
-          } else {
-            index
-          };
-
-          match s {
+      impl RFC {
+        /// Returns the [`Filter`] flag of the RFC
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        /// use iprfc::{Filter, RFC6890, FORWARDING_BLACKLIST};
+        ///
+        /// assert_eq!(Filter::RFC6890, RFC6890.filter());
+        /// assert_eq!(Filter::FORWARDING_BLACKLIST, FORWARDING_BLACKLIST.filter());
+        /// ```
+        #[inline]
+        pub const fn filter(&self) -> Filter {
+          match self.id {
             $(
-              stringify!($id) => &RFCS[$index],
+              $id => Filter::[<RFC $id>],
             )+
-            "blacklist" | "BLACKLIST" | "blocked" | "BLOCKED" => RFCS.last().unwrap(),
-            val if val == stringify!(FORWARDING_BLACKLIST_ID) => RFCS.last().unwrap(),
-            val => panic!("{val} is not a valid RFC identifier"),
+            FORWARDING_BLACKLIST_ID => Filter::FORWARDING_BLACKLIST,
+            _ => Filter::empty(),
           }
         }
       }
@@ -93,7 +142,7 @@ macro_rules! rfcs {
         /// ```rust
         /// use iprfc::RFCs;
         ///
-        /// assert_eq!(30, RFCs::len());
+        /// assert_eq!(45, RFCs::len());
         /// ```
         #[inline]
         pub const fn len() -> usize {
@@ -171,7 +220,7 @@ macro_rules! rfcs {
 }
 
 rfcs! {
-  (0, 919), (1, 1112), (2, 1122), (3, 1918), (4, 2544), (5, 2765), (6, 2928), (7, 3056), (8, 3068), (9, 3171), (10, 3330), (11, 3849), (12, 3927), (13, 4038), (14, 4193), (15, 4291), (16, 4380), (17, 4773), (18, 4843), (19, 5180), (20, 5735), (21, 5737), (22, 6052), (23, 6333), (24, 6598), (25, 6666), (26, 6890), (27, 7335), (28, 9637),
+  (0, 919), (1, 1112), (2, 1122), (3, 1918), (4, 2544), (5, 2765), (6, 2928), (7, 3056), (8, 3068), (9, 3171), (10, 3330), (11, 3849), (12, 3927), (13, 4038), (14, 4193), (15, 4291), (16, 4380), (17, 4773), (18, 4843), (19, 5180), (20, 5735), (21, 5737), (22, 6052), (23, 6333), (24, 6598), (25, 6666), (26, 6890), (27, 7335), (28, 9637), (29, 7450), (30, 7534), (31, 7535), (32, 7600), (33, 7723), (34, 8155), (35, 8215), (36, 8880), (37, 7343), (38, 9374), (39, 9602), (40, 5771), (41, 4607), (42, 3180), (43, 2365),
 }
 
 /// All known RFCs
@@ -202,6 +251,55 @@ impl RFCs {
     RFCS.iter()
   }
 
+  /// Returns the [`Filter`] of every known RFC, including the
+  /// [`FORWARDING_BLACKLIST`], that contains the ip
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{RFCs, Filter};
+  /// use std::net::{Ipv4Addr, Ipv6Addr};
+  ///
+  /// let filter = RFCs::classify(&Ipv4Addr::new(192, 168, 1, 1));
+  /// assert!(filter.contains(Filter::RFC1918 | Filter::RFC6890));
+  /// assert!(!filter.contains(Filter::FORWARDING_BLACKLIST));
+  ///
+  /// let filter = RFCs::classify(&Ipv6Addr::LOCALHOST);
+  /// assert!(filter.contains(Filter::RFC4291 | Filter::FORWARDING_BLACKLIST));
+  ///
+  /// assert!(RFCs::classify(&Ipv4Addr::new(8, 8, 8, 8)).is_empty());
+  /// ```
+  #[inline]
+  pub fn classify<T>(ip: &T) -> Filter
+  where
+    PrefixTrie: Lookup<T>,
+  {
+    PrefixTrie::builtin().classify(ip)
+  }
+
+  /// Returns the most specific special-purpose [`Block`] containing the ip,
+  /// searching the blocks of every known RFC.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::RFCs;
+  /// use std::net::Ipv4Addr;
+  ///
+  /// // An egress filter can ask whether an address is a valid source.
+  /// let valid_source = |ip: &Ipv4Addr| RFCs::block(ip).map_or(true, |b| b.source());
+  /// assert!(valid_source(&Ipv4Addr::new(10, 0, 0, 1)));
+  /// assert!(!valid_source(&Ipv4Addr::new(192, 0, 2, 1)));
+  /// assert!(valid_source(&Ipv4Addr::new(8, 8, 8, 8)));
+  /// ```
+  #[inline]
+  pub fn block<T>(ip: &T) -> Option<&'static Block>
+  where
+    Block: Contains<T>,
+  {
+    block::most_specific(RFCS.iter().flat_map(|rfc| rfc.blocks), ip)
+  }
+
   /// Returns a subset of [`RFCs`] by filtering with the given [`Filter`]
   ///
   /// ## Example
@@ -217,6 +315,24 @@ impl RFCs {
   }
 }
 
+impl ::core::ops::Index<&str> for RFCs {
+  type Output = RFC;
+
+  /// Returns the RFC parsed from the string, see [`RFC`]'s
+  /// [`FromStr`](core::str::FromStr) implementation for the accepted forms.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if the string is not a valid RFC identifier, use
+  /// [`str::parse`] to handle the error instead.
+  fn index(&self, index: &str) -> &Self::Output {
+    match parse::parse(index) {
+      Ok(rfc) => rfc,
+      Err(e) => panic!("{index:?}: {e}"),
+    }
+  }
+}
+
 /// A subset of [`RFCs`], useful for filtering
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Subset(Filter);
@@ -236,9 +352,56 @@ impl Subset {
   /// ```
   pub fn contains<T>(&self, ip: &T) -> bool
   where
-    RFC: Contains<T>,
+    PrefixTrie: Lookup<T>,
   {
-    self.0.iter_names().any(|(n, _)| RFCs[n].contains(ip))
+    PrefixTrie::builtin().classify(ip).intersects(self.0)
+  }
+
+  /// Returns `true` if the subset contains the IPv4 address, usable in const
+  /// contexts.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Filter, RFCs, Subset};
+  /// use std::net::Ipv4Addr;
+  ///
+  /// const LOCAL: Subset = RFCs::filter(Filter::RFC1918.union(Filter::RFC6598));
+  /// const SHARED: bool = LOCAL.contains_v4_const(Ipv4Addr::new(100, 64, 0, 1));
+  /// assert!(SHARED);
+  /// ```
+  pub const fn contains_v4_const(&self, ip: Ipv4Addr) -> bool {
+    let mut i = 0;
+    while i < RFCS.len() {
+      if self.0.intersects(RFCS[i].filter()) && RFCS[i].contains_v4_const(ip) {
+        return true;
+      }
+      i += 1;
+    }
+    false
+  }
+
+  /// Returns `true` if the subset contains the IPv6 address, usable in const
+  /// contexts.
+  pub const fn contains_v6_const(&self, ip: Ipv6Addr) -> bool {
+    let mut i = 0;
+    while i < RFCS.len() {
+      if self.0.intersects(RFCS[i].filter()) && RFCS[i].contains_v6_const(ip) {
+        return true;
+      }
+      i += 1;
+    }
+    false
+  }
+
+  /// Returns `true` if the subset contains the IP address, usable in const
+  /// contexts.
+  #[inline]
+  pub const fn contains_const(&self, ip: IpAddr) -> bool {
+    match ip {
+      IpAddr::V4(ip) => self.contains_v4_const(ip),
+      IpAddr::V6(ip) => self.contains_v6_const(ip),
+    }
   }
 }
 
@@ -249,6 +412,7 @@ pub struct RFC {
   ip_nets: &'static [IpNet],
   ipv4_nets: &'static [Ipv4Net],
   ipv6_nets: &'static [Ipv6Net],
+  blocks: &'static [Block],
 }
 
 impl RFC {
@@ -313,6 +477,47 @@ impl RFC {
     self.ipv6_nets
   }
 
+  /// Get the special-purpose registry entries recorded by the RFC
+  ///
+  /// [`RFC6890`] carries the registry table it defines, and RFCs allocating
+  /// special-purpose blocks after it carry their own entries. Other RFCs
+  /// return an empty slice.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::RFC6890;
+  ///
+  /// for block in RFC6890.blocks() {
+  ///   println!("{} {} forwardable={}", block.net(), block.name(), block.forwardable());
+  /// }
+  /// ```
+  #[inline]
+  pub const fn blocks(&self) -> &'static [Block] {
+    self.blocks
+  }
+
+  /// Returns the most specific [`Block`] of the RFC containing the ip.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::RFC6890;
+  /// use std::net::Ipv4Addr;
+  ///
+  /// let block = RFC6890.block(&Ipv4Addr::new(192, 0, 0, 1)).unwrap();
+  /// assert_eq!("192.0.0.0/29", block.net().to_string());
+  ///
+  /// assert!(RFC6890.block(&Ipv4Addr::new(8, 8, 8, 8)).is_none());
+  /// ```
+  #[inline]
+  pub fn block<T>(&self, ip: &T) -> Option<&'static Block>
+  where
+    Block: Contains<T>,
+  {
+    block::most_specific(self.blocks.iter(), ip)
+  }
+
   /// Returns `true` if the ip is contained by the [`RFC`].
   ///
   /// ## Example
@@ -380,11 +585,90 @@ impl RFC {
   {
     Contains::contains(self, ip)
   }
+
+  /// Returns `true` if the IPv4 address is contained by the [`RFC`], usable
+  /// in const contexts.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::RFC1918;
+  /// use std::net::Ipv4Addr;
+  ///
+  /// const GATEWAY: Ipv4Addr = Ipv4Addr::new(192, 168, 1, 1);
+  /// const _: () = assert!(RFC1918.contains_v4_const(GATEWAY));
+  /// assert!(!RFC1918.contains_v4_const(Ipv4Addr::new(8, 8, 8, 8)));
+  /// ```
+  pub const fn contains_v4_const(&self, ip: Ipv4Addr) -> bool {
+    let mut i = 0;
+    while i < self.ipv4_nets.len() {
+      if ipv4_net_contains(self.ipv4_nets[i], ip) {
+        return true;
+      }
+      i += 1;
+    }
+    false
+  }
+
+  /// Returns `true` if the IPv6 address is contained by the [`RFC`], usable
+  /// in const contexts.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::RFC4193;
+  /// use std::net::Ipv6Addr;
+  ///
+  /// const _: () = assert!(RFC4193.contains_v6_const(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1)));
+  /// ```
+  pub const fn contains_v6_const(&self, ip: Ipv6Addr) -> bool {
+    let mut i = 0;
+    while i < self.ipv6_nets.len() {
+      if ipv6_net_contains(self.ipv6_nets[i], ip) {
+        return true;
+      }
+      i += 1;
+    }
+    false
+  }
+
+  /// Returns `true` if the IP address is contained by the [`RFC`], usable in
+  /// const contexts.
+  #[inline]
+  pub const fn contains_const(&self, ip: IpAddr) -> bool {
+    match ip {
+      IpAddr::V4(ip) => self.contains_v4_const(ip),
+      IpAddr::V6(ip) => self.contains_v6_const(ip),
+    }
+  }
+}
+
+/// Returns the mask of the first `len` bits of a 128-bit address.
+#[inline]
+pub(crate) const fn prefix_mask(len: u8) -> u128 {
+  match len {
+    0 => 0,
+    len => u128::MAX << (128 - len as u32),
+  }
 }
 
-/// Returns `true` if the [`RFC`] contains `T`.
+/// Returns `true` if the network contains the address, in const contexts.
+#[inline]
+pub(crate) const fn ipv4_net_contains(net: Ipv4Net, ip: Ipv4Addr) -> bool {
+  let mask = (prefix_mask(net.prefix_len()) >> 96) as u32;
+  net.addr().to_bits() & mask == ip.to_bits() & mask
+}
+
+/// Returns `true` if the network contains the address, in const contexts.
+#[inline]
+pub(crate) const fn ipv6_net_contains(net: Ipv6Net, ip: Ipv6Addr) -> bool {
+  let mask = prefix_mask(net.prefix_len());
+  net.addr().to_bits() & mask == ip.to_bits() & mask
+}
+
+/// Returns `true` if the [`RFC`] or [`Block`] contains `T`.
 pub trait Contains<T>: sealed::Sealed {
-  /// Returns `true` if the [`RFC`] contains `T`.
+  /// Returns `true` if the [`RFC`] or [`Block`] contains `T`.
   fn contains(&self, t: &T) -> bool;
 }
 
@@ -434,6 +718,13 @@ mod sealed {
   pub trait Sealed {}
 
   impl Sealed for super::RFC {}
+  impl Sealed for super::Block {}
+  impl Sealed for super::PrefixTrie {}
+  #[cfg(feature = "alloc")]
+  impl Sealed for super::Registry {}
+  impl Sealed for core::net::IpAddr {}
+  impl Sealed for core::net::Ipv4Addr {}
+  impl Sealed for core::net::Ipv6Addr {}
 }
 
 #[test]
@@ -488,6 +779,9 @@ fn test_indexable_by_str() {
 
   let rfc = RFCs["blacklist"];
   assert_eq!(rfc.id(), FORWARDING_BLACKLIST_ID);
+
+  let rfc = RFCs["Rfc-1918"];
+  assert_eq!(1918, rfc.id());
 }
 
 #[test]
@@ -495,3 +789,119 @@ fn test_indexable_by_str() {
 fn test_indexable_by_str_panic() {
   let _ = RFCs["9999"];
 }
+
+#[test]
+fn test_block() {
+  let ip: Ipv4Addr = "10.1.2.3".parse().unwrap();
+  let block = RFCs::block(&ip).unwrap();
+  assert_eq!(1918, block.rfc());
+  assert!(block.source() && block.forwardable());
+  assert_eq!(Some(false), block.global());
+
+  let net: Ipv6Net = "fe80::/64".parse().unwrap();
+  let block = RFCs::block(&net).unwrap();
+  assert!(!block.forwardable());
+  assert!(block.reserved_by_protocol());
+
+  assert!(RFCs::block(&Ipv6Addr::new(0x2a00, 0, 0, 0, 0, 0, 0, 1)).is_none());
+}
+
+#[test]
+fn test_classify() {
+  use core::str::FromStr;
+
+  for rfc in RFCs::iter() {
+    for net in rfc.ip_nets() {
+      assert!(RFCs::classify(net).contains(rfc.filter()), "{net}");
+      assert!(RFCs::classify(&net.addr()).contains(rfc.filter()), "{net}");
+    }
+  }
+
+  let filter = RFCs::classify(&Ipv4Addr::new(100, 64, 0, 1));
+  assert_eq!(filter, Filter::RFC6598 | Filter::RFC6890);
+
+  let net = Ipv6Net::from_str("2001:db8:1::/48").unwrap();
+  assert_eq!(
+    RFCs::classify(&net),
+    Filter::RFC2928 | Filter::RFC3849 | Filter::RFC6890 | Filter::FORWARDING_BLACKLIST
+  );
+  assert_eq!(RFCs::classify(&IpNet::V6(net)), RFCs::classify(&net));
+
+  assert!(RFCs::classify(&Ipv6Addr::new(0x2a00, 0, 0, 0, 0, 0, 0, 1)).is_empty());
+}
+
+#[test]
+fn test_block_after_rfc6890() {
+  for (s, rfc) in [
+    ("192.0.0.8", 7600),
+    ("192.0.0.9", 7723),
+    ("192.0.0.10", 8155),
+    ("192.0.0.170", 8880),
+    ("192.0.0.171", 8880),
+    ("192.0.0.172", 6890),
+    ("192.31.196.1", 7535),
+    ("192.52.193.1", 7450),
+    ("192.175.48.1", 7534),
+    ("64:ff9b:1::1", 8215),
+    ("2001:1::1", 7723),
+    ("2001:1::2", 8155),
+    ("2001:3::1", 7450),
+    ("2001:4:112::1", 7535),
+    ("2001:20::1", 7343),
+    ("2001:30::1", 9374),
+    ("2620:4f:8000::1", 7534),
+    ("3fff::1", 9637),
+    ("5f00::1", 9602),
+  ] {
+    let ip: IpAddr = s.parse().unwrap();
+    assert_eq!(RFCs::block(&ip).unwrap().rfc(), rfc, "{s}");
+    assert!(RFCs::classify(&ip).contains(RFCs[rfc].filter()), "{s}");
+  }
+}
+
+#[test]
+fn test_contains_const() {
+  for s in [
+    "0.0.0.1",
+    "10.1.2.3",
+    "100.64.0.1",
+    "127.0.0.1",
+    "169.254.1.1",
+    "192.0.0.9",
+    "192.0.2.1",
+    "192.88.99.1",
+    "198.18.0.1",
+    "224.0.0.251",
+    "239.255.255.250",
+    "240.0.0.1",
+    "255.255.255.255",
+    "8.8.8.8",
+    "::",
+    "::1",
+    "::ffff:10.0.0.1",
+    "64:ff9b::1",
+    "2001::1",
+    "2001:2::1",
+    "2001:db8::1",
+    "2002::1",
+    "3fff::1",
+    "fc00::1",
+    "fe80::1",
+    "ff02::1",
+    "2a00::1",
+  ] {
+    let ip: IpAddr = s.parse().unwrap();
+    for rfc in RFCs::iter() {
+      assert_eq!(
+        rfc.contains_const(ip),
+        rfc.contains(&ip),
+        "{s} {}",
+        rfc.id()
+      );
+    }
+    let subset = RFCs::filter(Filter::all());
+    assert_eq!(subset.contains_const(ip), subset.contains(&ip), "{s}");
+    let subset = RFCs::filter(Filter::RFC1918 | Filter::RFC4193);
+    assert_eq!(subset.contains_const(ip), subset.contains(&ip), "{s}");
+  }
+}
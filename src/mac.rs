@@ -0,0 +1,354 @@
+use core::{
+  fmt,
+  net::{IpAddr, Ipv4Addr, Ipv6Addr},
+  str::FromStr,
+};
+
+use ipnet::Ipv6Net;
+
+/// A 48-bit IEEE 802 MAC address.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::MacAddr;
+/// use std::net::Ipv4Addr;
+///
+/// let mac = MacAddr::from_ipv4_multicast(Ipv4Addr::new(224, 0, 0, 251)).unwrap();
+/// assert_eq!("01:00:5e:00:00:fb", mac.to_string());
+/// assert_eq!(mac, "01-00-5E-00-00-FB".parse().unwrap());
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct MacAddr([u8; 6]);
+
+impl MacAddr {
+  /// The broadcast address `ff:ff:ff:ff:ff:ff`.
+  pub const BROADCAST: Self = Self([0xff; 6]);
+
+  /// Creates a MAC address from its six octets.
+  #[inline]
+  pub const fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> Self {
+    Self([a, b, c, d, e, f])
+  }
+
+  /// Returns the six octets of the MAC address.
+  #[inline]
+  pub const fn octets(&self) -> [u8; 6] {
+    self.0
+  }
+
+  /// Returns `true` if the individual/group bit is set.
+  #[inline]
+  pub const fn is_multicast(&self) -> bool {
+    self.0[0] & 0x01 != 0
+  }
+
+  /// Returns `true` if the universal/local bit is set, i.e. the address is
+  /// locally administered.
+  #[inline]
+  pub const fn is_local(&self) -> bool {
+    self.0[0] & 0x02 != 0
+  }
+
+  /// Returns `true` if this is the broadcast address.
+  #[inline]
+  pub const fn is_broadcast(&self) -> bool {
+    let [a, b, c, d, e, f] = self.0;
+    a & b & c & d & e & f == 0xff
+  }
+
+  /// Returns the MAC address of an IPv4 multicast group, `01:00:5e` followed
+  /// by the low 23 bits of the group
+  /// ([RFC 1112 §6.4](https://datatracker.ietf.org/doc/html/rfc1112#section-6.4)),
+  /// or `None` if the address is not multicast.
+  #[inline]
+  pub const fn from_ipv4_multicast(ip: Ipv4Addr) -> Option<Self> {
+    let [a, b, c, d] = ip.octets();
+    if a & 0xf0 != 224 {
+      return None;
+    }
+    Some(Self([0x01, 0x00, 0x5e, b & 0x7f, c, d]))
+  }
+
+  /// Returns the MAC address of an IPv6 multicast group, `33:33` followed
+  /// by the low 32 bits of the group
+  /// ([RFC 2464 §7](https://datatracker.ietf.org/doc/html/rfc2464#section-7)),
+  /// or `None` if the address is not multicast.
+  #[inline]
+  pub const fn from_ipv6_multicast(ip: Ipv6Addr) -> Option<Self> {
+    let o = ip.octets();
+    if o[0] != 0xff {
+      return None;
+    }
+    Some(Self([0x33, 0x33, o[12], o[13], o[14], o[15]]))
+  }
+
+  /// Returns the MAC address of an IPv4 or IPv6 multicast group.
+  #[inline]
+  pub const fn from_multicast(ip: IpAddr) -> Option<Self> {
+    match ip {
+      IpAddr::V4(ip) => Self::from_ipv4_multicast(ip),
+      IpAddr::V6(ip) => Self::from_ipv6_multicast(ip),
+    }
+  }
+
+  /// Returns the 32 IPv4 multicast groups mapped to this MAC address, or
+  /// `None` if it is not in `01:00:5e:00:00:00/25`.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::MacAddr;
+  /// use std::net::Ipv4Addr;
+  ///
+  /// let groups = MacAddr::new(0x01, 0x00, 0x5e, 0x00, 0x00, 0x01).ipv4_multicast_groups().unwrap();
+  /// assert_eq!(Ipv4Addr::new(224, 0, 0, 1), groups[0]);
+  /// assert_eq!(Ipv4Addr::new(224, 128, 0, 1), groups[1]);
+  /// assert_eq!(Ipv4Addr::new(239, 128, 0, 1), groups[31]);
+  /// ```
+  pub const fn ipv4_multicast_groups(&self) -> Option<[Ipv4Addr; 32]> {
+    let [a, b, c, d, e, f] = self.0;
+    if a != 0x01 || b != 0x00 || c != 0x5e || d & 0x80 != 0 {
+      return None;
+    }
+
+    let mut groups = [Ipv4Addr::UNSPECIFIED; 32];
+    let mut i = 0;
+    while i < 32 {
+      let first = 224 | (i as u8 >> 1);
+      let second = d | ((i as u8 & 1) << 7);
+      groups[i] = Ipv4Addr::new(first, second, e, f);
+      i += 1;
+    }
+    Some(groups)
+  }
+
+  /// Returns the low 32 bits shared by every IPv6 multicast group mapped to
+  /// this MAC address, or `None` if it is not in `33:33:00:00:00:00/16`.
+  #[inline]
+  pub const fn ipv6_multicast_bits(&self) -> Option<u32> {
+    let [a, b, c, d, e, f] = self.0;
+    if a != 0x33 || b != 0x33 {
+      return None;
+    }
+    Some(u32::from_be_bytes([c, d, e, f]))
+  }
+
+  /// Returns the modified EUI-64 interface identifier of this MAC address:
+  /// `ff:fe` inserted in the middle and the universal/local bit inverted
+  /// ([RFC 4291 Appendix A]).
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::MacAddr;
+  ///
+  /// let mac = MacAddr::new(0x00, 0xaa, 0x00, 0x28, 0x9c, 0x5a);
+  /// assert_eq!(0x02aa_00ff_fe28_9c5a, mac.to_modified_eui64());
+  /// assert_eq!(Some(mac), MacAddr::from_modified_eui64(0x02aa_00ff_fe28_9c5a));
+  /// ```
+  ///
+  /// [RFC 4291 Appendix A]: https://datatracker.ietf.org/doc/html/rfc4291#appendix-A
+  #[inline]
+  pub const fn to_modified_eui64(&self) -> u64 {
+    let [a, b, c, d, e, f] = self.0;
+    u64::from_be_bytes([a ^ 0x02, b, c, 0xff, 0xfe, d, e, f])
+  }
+
+  /// Recovers the MAC address from a modified EUI-64 interface identifier,
+  /// or returns `None` if the identifier does not have `ff:fe` in its middle
+  /// octets.
+  #[inline]
+  pub const fn from_modified_eui64(iid: u64) -> Option<Self> {
+    let [a, b, c, d, e, f, g, h] = iid.to_be_bytes();
+    if d != 0xff || e != 0xfe {
+      return None;
+    }
+    Some(Self([a ^ 0x02, b, c, f, g, h]))
+  }
+
+  /// Recovers the MAC address from an IPv6 address whose interface
+  /// identifier is modified EUI-64, e.g. a SLAAC address.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::MacAddr;
+  ///
+  /// let mac = MacAddr::from_ipv6("2001:db8::2aa:ff:fe28:9c5a".parse().unwrap()).unwrap();
+  /// assert_eq!("00:aa:00:28:9c:5a", mac.to_string());
+  /// ```
+  #[inline]
+  pub const fn from_ipv6(ip: Ipv6Addr) -> Option<Self> {
+    Self::from_modified_eui64(ip.to_bits() as u64)
+  }
+
+  /// Builds the IPv6 address made of `prefix` and the modified EUI-64
+  /// interface identifier of this MAC address, or returns `None` if the
+  /// prefix is longer than 64 bits.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::MacAddr;
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let mac = MacAddr::new(0x00, 0xaa, 0x00, 0x28, 0x9c, 0x5a);
+  /// let ip = mac.to_ipv6("fe80::/64".parse().unwrap()).unwrap();
+  /// assert_eq!("fe80::2aa:ff:fe28:9c5a".parse::<Ipv6Addr>().unwrap(), ip);
+  /// ```
+  #[inline]
+  pub const fn to_ipv6(&self, prefix: Ipv6Net) -> Option<Ipv6Addr> {
+    if prefix.prefix_len() > 64 {
+      return None;
+    }
+    let network = prefix.addr().to_bits() >> 64 << 64;
+    Some(Ipv6Addr::from_bits(
+      network | self.to_modified_eui64() as u128,
+    ))
+  }
+}
+
+impl From<[u8; 6]> for MacAddr {
+  #[inline]
+  fn from(octets: [u8; 6]) -> Self {
+    Self(octets)
+  }
+}
+
+impl From<MacAddr> for [u8; 6] {
+  #[inline]
+  fn from(mac: MacAddr) -> Self {
+    mac.0
+  }
+}
+
+impl fmt::Display for MacAddr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let [a, b, c, d, e, g] = self.0;
+    write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+  }
+}
+
+/// An error returned when parsing a [`MacAddr`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ParseMacAddrError;
+
+impl fmt::Display for ParseMacAddrError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid MAC address syntax")
+  }
+}
+
+impl core::error::Error for ParseMacAddrError {}
+
+impl FromStr for MacAddr {
+  type Err = ParseMacAddrError;
+
+  /// Parses six hexadecimal octets separated by `:` or `-`.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let sep = if s.contains('-') { '-' } else { ':' };
+    let mut octets = [0; 6];
+    let mut parts = s.split(sep);
+    for octet in &mut octets {
+      let part = parts.next().ok_or(ParseMacAddrError)?;
+      if part.len() != 2 || !part.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseMacAddrError);
+      }
+      *octet = u8::from_str_radix(part, 16).map_err(|_| ParseMacAddrError)?;
+    }
+    match parts.next() {
+      Some(_) => Err(ParseMacAddrError),
+      None => Ok(Self(octets)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  #[test]
+  fn multicast_mapping() {
+    for (ip, mac) in [
+      ("224.0.0.1", "01:00:5e:00:00:01"),
+      ("239.255.255.250", "01:00:5e:7f:ff:fa"),
+      ("224.128.0.1", "01:00:5e:00:00:01"),
+      ("ff02::1", "33:33:00:00:00:01"),
+      ("ff02::1:ff28:9c5a", "33:33:ff:28:9c:5a"),
+    ] {
+      let ip: IpAddr = ip.parse().unwrap();
+      let mac: MacAddr = mac.parse().unwrap();
+      assert_eq!(MacAddr::from_multicast(ip), Some(mac), "{ip}");
+      assert!(mac.is_multicast());
+      match ip {
+        IpAddr::V4(ip) => assert!(mac.ipv4_multicast_groups().unwrap().contains(&ip)),
+        IpAddr::V6(ip) => assert_eq!(mac.ipv6_multicast_bits(), Some(ip.to_bits() as u32)),
+      }
+    }
+
+    assert_eq!(MacAddr::from_multicast("10.0.0.1".parse().unwrap()), None);
+    assert_eq!(MacAddr::from_multicast("fe80::1".parse().unwrap()), None);
+
+    let groups = MacAddr::new(0x01, 0x00, 0x5e, 0x7f, 0xff, 0xfa)
+      .ipv4_multicast_groups()
+      .unwrap();
+    for group in groups {
+      assert_eq!(
+        MacAddr::from_ipv4_multicast(group).unwrap().to_string(),
+        "01:00:5e:7f:ff:fa"
+      );
+    }
+    assert!(MacAddr::new(0x01, 0x00, 0x5e, 0x80, 0, 1)
+      .ipv4_multicast_groups()
+      .is_none());
+    assert!(MacAddr::BROADCAST.ipv6_multicast_bits().is_none());
+  }
+
+  #[test]
+  fn modified_eui64() {
+    // RFC 4291 Appendix A: the U/L bit is inverted
+    let mac = MacAddr::new(0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde);
+    assert_eq!(mac.to_modified_eui64(), 0x3656_78ff_fe9a_bcde);
+    let local = MacAddr::new(0x02, 0, 0, 0, 0, 1);
+    assert_eq!(local.to_modified_eui64(), 0x0000_00ff_fe00_0001);
+    assert_eq!(
+      MacAddr::from_modified_eui64(0x0000_00ff_fe00_0001),
+      Some(local)
+    );
+    assert_eq!(MacAddr::from_modified_eui64(0x0000_00ff_ff00_0001), None);
+
+    let ip = mac.to_ipv6("2001:db8:1::/48".parse().unwrap()).unwrap();
+    assert_eq!(
+      ip,
+      "2001:db8:1:0:3656:78ff:fe9a:bcde"
+        .parse::<Ipv6Addr>()
+        .unwrap()
+    );
+    assert_eq!(MacAddr::from_ipv6(ip), Some(mac));
+    assert!(crate::is_eui64_ipv6_addr(ip));
+    assert_eq!(mac.to_ipv6("2001:db8::/96".parse().unwrap()), None);
+    assert_eq!(MacAddr::from_ipv6("2001:db8::1".parse().unwrap()), None);
+  }
+
+  #[test]
+  fn parse() {
+    assert!(MacAddr::BROADCAST.is_broadcast());
+    assert_eq!("ff:ff:ff:ff:ff:ff".parse(), Ok(MacAddr::BROADCAST));
+    for s in [
+      "",
+      "01:00:5e:00:00",
+      "01:00:5e:00:00:01:02",
+      "1:0:5e:0:0:1",
+      "01:00-5e:00:00:01",
+      "0g:00:5e:00:00:01",
+      "+1:+2:+3:+4:+5:+6",
+    ] {
+      assert_eq!(s.parse::<MacAddr>(), Err(ParseMacAddrError), "{s}");
+    }
+    let mac = MacAddr::new(0x02, 0, 0, 0, 0, 1);
+    assert!(mac.is_local() && !mac.is_multicast());
+  }
+}
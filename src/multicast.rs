@@ -0,0 +1,845 @@
+use core::{
+  cmp::Ordering,
+  fmt,
+  net::{Ipv4Addr, Ipv6Addr},
+};
+
+use ipnet::{Ipv4Net, Ipv6Net};
+
+use super::{ipv4_net_contains, prefix_mask, rfc2365, rfc5771, RFC3180, RFC4607};
+
+/// The scope of a multicast address, as defined by
+/// [RFC 4291 §2.7](https://datatracker.ietf.org/doc/html/rfc4291#section-2.7)
+/// and [RFC 7346](https://datatracker.ietf.org/doc/html/rfc7346).
+///
+/// Scopes are ordered by their value, from the smallest to the largest.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum MulticastScope {
+  /// Interface-local scope (`0x1`), for loopback transmission only.
+  InterfaceLocal,
+  /// Link-local scope (`0x2`).
+  LinkLocal,
+  /// Realm-local scope (`0x3`), see RFC 7346.
+  RealmLocal,
+  /// Admin-local scope (`0x4`), the smallest administratively configured
+  /// scope.
+  AdminLocal,
+  /// Site-local scope (`0x5`).
+  SiteLocal,
+  /// Organization-local scope (`0x8`).
+  OrganizationLocal,
+  /// Global scope (`0xe`).
+  Global,
+  /// A reserved scope value, `0x0` or `0xf`.
+  Reserved(u8),
+  /// An unassigned scope value, available to administrators.
+  Unassigned(u8),
+}
+
+impl MulticastScope {
+  /// Returns the scope of the 4-bit value. Bits above the low nibble are
+  /// ignored.
+  #[inline]
+  pub const fn from_value(value: u8) -> Self {
+    match value & 0xf {
+      0x1 => Self::InterfaceLocal,
+      0x2 => Self::LinkLocal,
+      0x3 => Self::RealmLocal,
+      0x4 => Self::AdminLocal,
+      0x5 => Self::SiteLocal,
+      0x8 => Self::OrganizationLocal,
+      0xe => Self::Global,
+      v @ (0x0 | 0xf) => Self::Reserved(v),
+      v => Self::Unassigned(v),
+    }
+  }
+
+  /// Returns the 4-bit value of the scope.
+  #[inline]
+  pub const fn value(&self) -> u8 {
+    match self {
+      Self::InterfaceLocal => 0x1,
+      Self::LinkLocal => 0x2,
+      Self::RealmLocal => 0x3,
+      Self::AdminLocal => 0x4,
+      Self::SiteLocal => 0x5,
+      Self::OrganizationLocal => 0x8,
+      Self::Global => 0xe,
+      Self::Reserved(v) | Self::Unassigned(v) => *v & 0xf,
+    }
+  }
+
+  /// Returns the name of the scope.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::InterfaceLocal => "interface-local",
+      Self::LinkLocal => "link-local",
+      Self::RealmLocal => "realm-local",
+      Self::AdminLocal => "admin-local",
+      Self::SiteLocal => "site-local",
+      Self::OrganizationLocal => "organization-local",
+      Self::Global => "global",
+      Self::Reserved(_) => "reserved",
+      Self::Unassigned(_) => "unassigned",
+    }
+  }
+}
+
+impl PartialOrd for MulticastScope {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for MulticastScope {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.value().cmp(&other.value())
+  }
+}
+
+impl fmt::Display for MulticastScope {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Reserved(v) | Self::Unassigned(v) => write!(f, "{} ({:#x})", self.name(), v),
+      _ => f.write_str(self.name()),
+    }
+  }
+}
+
+/// A view over an IPv6 multicast address (`ff00::/8`).
+///
+/// ```text
+/// |   8    |  4  |  4  |                  112 bits                   |
+/// +--------+-----+-----+---------------------------------------------+
+/// |11111111|flgs |scop |                  group ID                   |
+/// +--------+-----+-----+---------------------------------------------+
+/// ```
+///
+/// The flags are `0RPT`: `R` marks an embedded rendezvous point
+/// ([RFC 3956]), `P` a unicast-prefix-based address ([RFC 3306]) and `T` a
+/// transient address ([RFC 4291 §2.7]).
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{Ipv6Multicast, MulticastScope};
+/// use std::net::Ipv6Addr;
+///
+/// let group = Ipv6Multicast::new("ff7e:740:2001:db8:beef:feed::1234".parse().unwrap()).unwrap();
+/// assert_eq!(MulticastScope::Global, group.scope());
+/// assert!(group.is_transient() && group.is_prefix_based() && group.has_embedded_rp());
+/// assert_eq!(Some("2001:db8:beef:feed::/64".parse().unwrap()), group.unicast_prefix());
+/// assert_eq!(Some("2001:db8:beef:feed::7".parse::<Ipv6Addr>().unwrap()), group.rendezvous_point());
+/// ```
+///
+/// [RFC 3306]: https://datatracker.ietf.org/doc/rfc3306/
+/// [RFC 3956]: https://datatracker.ietf.org/doc/rfc3956/
+/// [RFC 4291 §2.7]: https://datatracker.ietf.org/doc/html/rfc4291#section-2.7
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ipv6Multicast(Ipv6Addr);
+
+impl Ipv6Multicast {
+  /// The `R` flag: a rendezvous point address is embedded.
+  pub const RENDEZVOUS_POINT: u8 = 0x4;
+  /// The `P` flag: the address is based on a unicast prefix.
+  pub const PREFIX: u8 = 0x2;
+  /// The `T` flag: the address is transient, i.e. not permanently assigned
+  /// by IANA.
+  pub const TRANSIENT: u8 = 0x1;
+
+  /// Returns a multicast view of the address, or `None` if it is not in
+  /// `ff00::/8`.
+  #[inline]
+  pub const fn new(ip: Ipv6Addr) -> Option<Self> {
+    if ip.octets()[0] == 0xff {
+      Some(Self(ip))
+    } else {
+      None
+    }
+  }
+
+  /// Builds a multicast address from its flags, scope and 112-bit group ID.
+  /// Extra bits of `flags` and `group_id` are ignored.
+  #[inline]
+  pub const fn from_parts(flags: u8, scope: MulticastScope, group_id: u128) -> Self {
+    let bits = (0xff << 120)
+      | (((flags & 0xf) as u128) << 116)
+      | ((scope.value() as u128) << 112)
+      | (group_id & GROUP_ID_MASK);
+    Self(Ipv6Addr::from_bits(bits))
+  }
+
+  /// Builds a unicast-prefix-based multicast address ([RFC 3306]), with the
+  /// `P` and `T` flags set.
+  ///
+  /// Returns `None` if the prefix is longer than 64 bits.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Ipv6Multicast, MulticastScope};
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let prefix = "2001:db8:1234::/48".parse().unwrap();
+  /// let group = Ipv6Multicast::from_unicast_prefix(prefix, MulticastScope::SiteLocal, 1).unwrap();
+  /// assert_eq!("ff35:30:2001:db8:1234::1".parse::<Ipv6Addr>().unwrap(), group.addr());
+  /// ```
+  ///
+  /// [RFC 3306]: https://datatracker.ietf.org/doc/rfc3306/
+  #[inline]
+  pub const fn from_unicast_prefix(
+    prefix: Ipv6Net,
+    scope: MulticastScope,
+    group_id: u32,
+  ) -> Option<Self> {
+    Self::prefix_based(Self::PREFIX | Self::TRANSIENT, 0, prefix, scope, group_id)
+  }
+
+  /// Builds a multicast address embedding the rendezvous point `rp`
+  /// ([RFC 3956]), with the `R`, `P` and `T` flags set.
+  ///
+  /// The rendezvous point is made of the first `prefix_len` bits of `rp`,
+  /// followed by zeros and its 4-bit interface ID (RIID). Returns `None` if
+  /// `prefix_len` is not in `1..=64` or if `rp` does not have this form.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Ipv6Multicast, MulticastScope};
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let rp = "2001:db8:beef:feed::7".parse().unwrap();
+  /// let group = Ipv6Multicast::from_rendezvous_point(rp, 64, MulticastScope::Global, 0x1234).unwrap();
+  /// assert_eq!("ff7e:740:2001:db8:beef:feed:0:1234".parse::<Ipv6Addr>().unwrap(), group.addr());
+  /// ```
+  ///
+  /// [RFC 3956]: https://datatracker.ietf.org/doc/rfc3956/
+  pub const fn from_rendezvous_point(
+    rp: Ipv6Addr,
+    prefix_len: u8,
+    scope: MulticastScope,
+    group_id: u32,
+  ) -> Option<Self> {
+    if prefix_len == 0 || prefix_len > 64 {
+      return None;
+    }
+
+    let bits = rp.to_bits();
+    let riid = (bits & 0xf) as u8;
+    if bits & !prefix_mask(prefix_len) & !0xf != 0 {
+      return None;
+    }
+
+    let prefix = Ipv6Net::new_assert(
+      Ipv6Addr::from_bits(bits & prefix_mask(prefix_len)),
+      prefix_len,
+    );
+    Self::prefix_based(
+      Self::RENDEZVOUS_POINT | Self::PREFIX | Self::TRANSIENT,
+      riid,
+      prefix,
+      scope,
+      group_id,
+    )
+  }
+
+  /// Returns the solicited-node multicast address `ff02::1:ffXX:XXXX` of a
+  /// unicast or anycast address, made of its low 24 bits
+  /// ([RFC 4291 §2.7.1]).
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::Ipv6Multicast;
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let group = Ipv6Multicast::solicited_node("fe80::2aa:ff:fe28:9c5a".parse().unwrap());
+  /// assert_eq!("ff02::1:ff28:9c5a".parse::<Ipv6Addr>().unwrap(), group.addr());
+  /// assert_eq!(Some(0x28_9c5a), group.solicited_node_bits());
+  /// ```
+  ///
+  /// [RFC 4291 §2.7.1]: https://datatracker.ietf.org/doc/html/rfc4291#section-2.7.1
+  #[inline]
+  pub const fn solicited_node(ip: Ipv6Addr) -> Self {
+    Self(Ipv6Addr::from_bits(
+      SOLICITED_NODE | (ip.to_bits() & 0xff_ffff),
+    ))
+  }
+
+  /// Returns `true` if this is a solicited-node address, i.e. in
+  /// `ff02::1:ff00:0/104`.
+  #[inline]
+  pub const fn is_solicited_node(&self) -> bool {
+    self.0.to_bits() & !0xff_ffff == SOLICITED_NODE
+  }
+
+  /// Returns the low 24 bits shared by every address whose solicited-node
+  /// address is this one, or `None` if this is not a solicited-node address.
+  #[inline]
+  pub const fn solicited_node_bits(&self) -> Option<u32> {
+    if self.is_solicited_node() {
+      Some(self.0.to_bits() as u32 & 0xff_ffff)
+    } else {
+      None
+    }
+  }
+
+  const fn prefix_based(
+    flags: u8,
+    riid: u8,
+    prefix: Ipv6Net,
+    scope: MulticastScope,
+    group_id: u32,
+  ) -> Option<Self> {
+    let len = prefix.prefix_len();
+    if len > 64 {
+      return None;
+    }
+
+    let network = (prefix.addr().to_bits() & prefix_mask(len)) >> 64;
+    let group =
+      ((riid as u128) << 104) | ((len as u128) << 96) | (network << 32) | group_id as u128;
+    Some(Self::from_parts(flags, scope, group))
+  }
+
+  /// Returns the multicast address.
+  #[inline]
+  pub const fn addr(&self) -> Ipv6Addr {
+    self.0
+  }
+
+  /// Returns the 4-bit flags.
+  #[inline]
+  pub const fn flags(&self) -> u8 {
+    self.0.octets()[1] >> 4
+  }
+
+  /// Returns `true` if the `R` flag is set, i.e. a rendezvous point address
+  /// is embedded.
+  #[inline]
+  pub const fn has_embedded_rp(&self) -> bool {
+    self.flags() & Self::RENDEZVOUS_POINT != 0
+  }
+
+  /// Returns `true` if the `P` flag is set, i.e. the address is based on a
+  /// unicast prefix.
+  #[inline]
+  pub const fn is_prefix_based(&self) -> bool {
+    self.flags() & Self::PREFIX != 0
+  }
+
+  /// Returns `true` if the `T` flag is set, i.e. the address is not
+  /// permanently assigned by IANA.
+  #[inline]
+  pub const fn is_transient(&self) -> bool {
+    self.flags() & Self::TRANSIENT != 0
+  }
+
+  /// Returns the scope.
+  #[inline]
+  pub const fn scope(&self) -> MulticastScope {
+    MulticastScope::from_value(self.0.octets()[1])
+  }
+
+  /// Returns the 112-bit group ID.
+  #[inline]
+  pub const fn group_id(&self) -> u128 {
+    self.0.to_bits() & GROUP_ID_MASK
+  }
+
+  /// Returns `true` if the address is a source-specific multicast address
+  /// `ff3x::/32` ([RFC 3306 §6](https://datatracker.ietf.org/doc/html/rfc3306#section-6)).
+  #[inline]
+  pub const fn is_source_specific(&self) -> bool {
+    self.flags() == Self::PREFIX | Self::TRANSIENT && (self.0.to_bits() >> 96) & 0xffff == 0
+  }
+
+  /// Returns the unicast prefix of a unicast-prefix-based address
+  /// ([RFC 3306]), or `None` if the `P` flag is not set, the address is
+  /// source-specific or the prefix length is invalid.
+  ///
+  /// [RFC 3306]: https://datatracker.ietf.org/doc/rfc3306/
+  pub const fn unicast_prefix(&self) -> Option<Ipv6Net> {
+    if !self.is_prefix_based() || self.is_source_specific() {
+      return None;
+    }
+
+    let len = self.0.octets()[3];
+    if len == 0 || len > 64 {
+      return None;
+    }
+
+    let network = ((self.0.to_bits() >> 32) << 64) & prefix_mask(len);
+    Some(Ipv6Net::new_assert(Ipv6Addr::from_bits(network), len))
+  }
+
+  /// Returns the 32-bit group ID of a unicast-prefix-based address, or
+  /// `None` if [`unicast_prefix`](Self::unicast_prefix) is `None`.
+  #[inline]
+  pub const fn prefix_group_id(&self) -> Option<u32> {
+    match self.unicast_prefix() {
+      Some(_) => Some(self.0.to_bits() as u32),
+      None => None,
+    }
+  }
+
+  /// Returns the embedded rendezvous point address ([RFC 3956]), or `None`
+  /// if the `R` flag is not set or there is no valid unicast prefix.
+  ///
+  /// [RFC 3956]: https://datatracker.ietf.org/doc/rfc3956/
+  pub const fn rendezvous_point(&self) -> Option<Ipv6Addr> {
+    if !self.has_embedded_rp() {
+      return None;
+    }
+
+    match self.unicast_prefix() {
+      Some(prefix) => {
+        let riid = (self.0.octets()[2] & 0xf) as u128;
+        Some(Ipv6Addr::from_bits(prefix.addr().to_bits() | riid))
+      }
+      None => None,
+    }
+  }
+}
+
+impl From<Ipv6Multicast> for Ipv6Addr {
+  #[inline]
+  fn from(multicast: Ipv6Multicast) -> Self {
+    multicast.0
+  }
+}
+
+impl fmt::Display for Ipv6Multicast {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+/// A registered block of the IPv4 multicast address space.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Ipv4MulticastBlock {
+  /// `224.0.0.0/24`, the Local Network Control Block
+  /// ([RFC 5771 §4](https://datatracker.ietf.org/doc/html/rfc5771#section-4)).
+  LocalNetworkControl,
+  /// `224.0.1.0/24`, the Internetwork Control Block
+  /// ([RFC 5771 §5](https://datatracker.ietf.org/doc/html/rfc5771#section-5)).
+  InternetworkControl,
+  /// `232.0.0.0/8`, Source-Specific Multicast
+  /// ([RFC 4607](https://datatracker.ietf.org/doc/rfc4607/)).
+  SourceSpecific,
+  /// `233.0.0.0/8` up to `233.251.255.255`, GLOP addressing
+  /// ([RFC 3180](https://datatracker.ietf.org/doc/rfc3180/)).
+  /// `233.252.0.0/14` is AD-HOC Block III since RFC 5771.
+  Glop,
+  /// `239.0.0.0/8`, Administratively Scoped
+  /// ([RFC 2365](https://datatracker.ietf.org/doc/rfc2365/)).
+  AdministrativelyScoped,
+  /// Any other multicast address.
+  Other,
+}
+
+impl Ipv4MulticastBlock {
+  /// Returns the RFC defining the block, or `None` for [`Other`](Self::Other).
+  #[inline]
+  pub const fn rfc(&self) -> Option<u32> {
+    match self {
+      Self::LocalNetworkControl | Self::InternetworkControl => Some(5771),
+      Self::SourceSpecific => Some(4607),
+      Self::Glop => Some(3180),
+      Self::AdministrativelyScoped => Some(2365),
+      Self::Other => None,
+    }
+  }
+}
+
+/// A view over an IPv4 multicast address (`224.0.0.0/4`).
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{Ipv4Multicast, Ipv4MulticastBlock, MulticastScope};
+/// use std::net::Ipv4Addr;
+///
+/// let group = Ipv4Multicast::new(Ipv4Addr::new(224, 0, 0, 251)).unwrap();
+/// assert_eq!(Ipv4MulticastBlock::LocalNetworkControl, group.block());
+/// assert_eq!(MulticastScope::LinkLocal, group.scope());
+/// assert!(!group.is_routable());
+///
+/// let group = Ipv4Multicast::new(Ipv4Addr::new(239, 192, 1, 1)).unwrap();
+/// assert_eq!(MulticastScope::OrganizationLocal, group.scope());
+/// assert!(group.is_routable());
+///
+/// let group = Ipv4Multicast::new(Ipv4Addr::new(233, 22, 30, 1)).unwrap();
+/// assert_eq!(Ipv4MulticastBlock::Glop, group.block());
+/// assert_eq!(Some(5662), group.glop_asn());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ipv4Multicast(Ipv4Addr);
+
+impl Ipv4Multicast {
+  /// Returns a multicast view of the address, or `None` if it is not in
+  /// `224.0.0.0/4`.
+  #[inline]
+  pub const fn new(ip: Ipv4Addr) -> Option<Self> {
+    if ip.octets()[0] & 0xf0 == 224 {
+      Some(Self(ip))
+    } else {
+      None
+    }
+  }
+
+  /// Returns the GLOP address `233.x.y.local` of the 16-bit autonomous
+  /// system number `x.y` ([RFC 3180 §2](https://datatracker.ietf.org/doc/html/rfc3180#section-2)),
+  /// or `None` if it falls in AD-HOC Block III.
+  #[inline]
+  pub const fn glop(asn: u16, local: u8) -> Option<Self> {
+    let [x, y] = asn.to_be_bytes();
+    let group = Self(Ipv4Addr::new(233, x, y, local));
+    match group.block() {
+      Ipv4MulticastBlock::Glop => Some(group),
+      _ => None,
+    }
+  }
+
+  /// Returns the multicast address.
+  #[inline]
+  pub const fn addr(&self) -> Ipv4Addr {
+    self.0
+  }
+
+  /// Returns the registered block of the address.
+  #[inline]
+  pub const fn block(&self) -> Ipv4MulticastBlock {
+    longest_match(BLOCKS, self.0, Ipv4MulticastBlock::Other)
+  }
+
+  /// Returns the scope of the address.
+  ///
+  /// - The Local Network Control Block is [link-local](MulticastScope::LinkLocal).
+  /// - The IPv4 Local Scope `239.255.0.0/16` is [site-local](MulticastScope::SiteLocal).
+  /// - The IPv4 Organization Local Scope `239.192.0.0/14` is
+  ///   [organization-local](MulticastScope::OrganizationLocal).
+  /// - The rest of `239.0.0.0/8` is [admin-local](MulticastScope::AdminLocal),
+  ///   its extent being configured by the administrator.
+  /// - Everything else is [global](MulticastScope::Global).
+  #[inline]
+  pub const fn scope(&self) -> MulticastScope {
+    longest_match(SCOPES, self.0, MulticastScope::Global)
+  }
+
+  /// Returns `true` if the group may be routed beyond the link, i.e. it is
+  /// not in the Local Network Control Block.
+  #[inline]
+  pub const fn is_routable(&self) -> bool {
+    !matches!(self.block(), Ipv4MulticastBlock::LocalNetworkControl)
+  }
+
+  /// Returns `true` if the address is a Source-Specific Multicast address.
+  #[inline]
+  pub const fn is_source_specific(&self) -> bool {
+    matches!(self.block(), Ipv4MulticastBlock::SourceSpecific)
+  }
+
+  /// Returns the 16-bit autonomous system number of a GLOP address.
+  #[inline]
+  pub const fn glop_asn(&self) -> Option<u16> {
+    match self.block() {
+      Ipv4MulticastBlock::Glop => {
+        let [_, x, y, _] = self.0.octets();
+        Some(u16::from_be_bytes([x, y]))
+      }
+      _ => None,
+    }
+  }
+}
+
+impl From<Ipv4Multicast> for Ipv4Addr {
+  #[inline]
+  fn from(multicast: Ipv4Multicast) -> Self {
+    multicast.0
+  }
+}
+
+impl fmt::Display for Ipv4Multicast {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+/// The IPv4 multicast blocks, with the prefixes of the RFC tables they span.
+const BLOCKS: &[(&[Ipv4Net], Ipv4MulticastBlock)] = &[
+  (
+    &[rfc5771::LOCAL_NETWORK_CONTROL],
+    Ipv4MulticastBlock::LocalNetworkControl,
+  ),
+  (
+    &[rfc5771::INTERNETWORK_CONTROL],
+    Ipv4MulticastBlock::InternetworkControl,
+  ),
+  (RFC4607.ipv4_nets, Ipv4MulticastBlock::SourceSpecific),
+  (RFC3180.ipv4_nets, Ipv4MulticastBlock::Glop),
+  (
+    &[rfc2365::ADMINISTRATIVELY_SCOPED],
+    Ipv4MulticastBlock::AdministrativelyScoped,
+  ),
+];
+
+/// The IPv4 multicast scopes, with the prefixes of the RFC tables they span.
+const SCOPES: &[(&[Ipv4Net], MulticastScope)] = &[
+  (&[rfc5771::LOCAL_NETWORK_CONTROL], MulticastScope::LinkLocal),
+  (&[rfc2365::LOCAL_SCOPE], MulticastScope::SiteLocal),
+  (
+    &[rfc2365::ORGANIZATION_LOCAL_SCOPE],
+    MulticastScope::OrganizationLocal,
+  ),
+  (
+    &[rfc2365::ADMINISTRATIVELY_SCOPED],
+    MulticastScope::AdminLocal,
+  ),
+];
+
+/// Returns the value of the longest prefix of the table containing the
+/// address, or `default` if none does.
+const fn longest_match<T: Copy>(table: &[(&[Ipv4Net], T)], ip: Ipv4Addr, default: T) -> T {
+  let mut found = default;
+  let mut len = None;
+  let mut i = 0;
+  while i < table.len() {
+    let (nets, value) = table[i];
+    let mut j = 0;
+    while j < nets.len() {
+      let net = nets[j];
+      let longer = match len {
+        Some(len) => net.prefix_len() > len,
+        None => true,
+      };
+      if longer && ipv4_net_contains(net, ip) {
+        found = value;
+        len = Some(net.prefix_len());
+      }
+      j += 1;
+    }
+    i += 1;
+  }
+  found
+}
+
+const SOLICITED_NODE: u128 = 0xff02_0000_0000_0000_0000_0001_ff00_0000;
+const GROUP_ID_MASK: u128 = (1 << 112) - 1;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn multicast(s: &str) -> Ipv6Multicast {
+    Ipv6Multicast::new(s.parse().unwrap()).unwrap()
+  }
+
+  #[test]
+  fn scope() {
+    for value in 0..16 {
+      assert_eq!(MulticastScope::from_value(value).value(), value);
+    }
+    assert_eq!(MulticastScope::from_value(0x3), MulticastScope::RealmLocal);
+    assert_eq!(
+      MulticastScope::from_value(0x6),
+      MulticastScope::Unassigned(6)
+    );
+    assert_eq!(
+      MulticastScope::from_value(0xf),
+      MulticastScope::Reserved(0xf)
+    );
+    assert!(MulticastScope::LinkLocal < MulticastScope::Unassigned(6));
+    assert!(MulticastScope::Unassigned(6) < MulticastScope::OrganizationLocal);
+    assert_eq!(multicast("ff02::1").scope(), MulticastScope::LinkLocal);
+    assert_eq!(multicast("ff05::1:3").scope(), MulticastScope::SiteLocal);
+  }
+
+  #[test]
+  fn flags() {
+    assert!(Ipv6Multicast::new("fe80::1".parse().unwrap()).is_none());
+
+    let group = multicast("ff02::1");
+    assert_eq!(group.flags(), 0);
+    assert!(!group.is_transient());
+    assert_eq!(group.group_id(), 1);
+    assert_eq!(group.unicast_prefix(), None);
+
+    let group = multicast("ff15::1234");
+    assert!(group.is_transient() && !group.is_prefix_based());
+    assert_eq!(
+      Ipv6Multicast::from_parts(Ipv6Multicast::TRANSIENT, MulticastScope::SiteLocal, 0x1234),
+      group
+    );
+  }
+
+  #[test]
+  fn unicast_prefix_based() {
+    // RFC 3306 §4
+    let group = multicast("ff3e:30:3ffe:ffff:1::8000:1");
+    assert_eq!(
+      group.unicast_prefix(),
+      Some("3ffe:ffff:1::/48".parse().unwrap())
+    );
+    assert_eq!(group.prefix_group_id(), Some(0x8000_0001));
+    assert_eq!(group.rendezvous_point(), None);
+    assert_eq!(
+      Ipv6Multicast::from_unicast_prefix(
+        "3ffe:ffff:1::/48".parse().unwrap(),
+        MulticastScope::Global,
+        0x8000_0001
+      ),
+      Some(group)
+    );
+    assert_eq!(
+      Ipv6Multicast::from_unicast_prefix(
+        "2001:db8::/96".parse().unwrap(),
+        MulticastScope::Global,
+        1
+      ),
+      None
+    );
+
+    let ssm = multicast("ff3e::8000:1");
+    assert!(ssm.is_source_specific());
+    assert_eq!(ssm.unicast_prefix(), None);
+  }
+
+  #[test]
+  fn embedded_rp() {
+    // RFC 3956 §3
+    let group = multicast("ff7e:140:2001:db8:beef:feed::1234");
+    assert_eq!(
+      group.rendezvous_point(),
+      Some("2001:db8:beef:feed::1".parse().unwrap())
+    );
+
+    let rp: Ipv6Addr = "2001:db8:100::f".parse().unwrap();
+    let group =
+      Ipv6Multicast::from_rendezvous_point(rp, 40, MulticastScope::OrganizationLocal, 7).unwrap();
+    assert_eq!(
+      group.addr(),
+      "ff78:f28:2001:db8:100::7".parse::<Ipv6Addr>().unwrap()
+    );
+    assert_eq!(group.rendezvous_point(), Some(rp));
+    assert_eq!(group.prefix_group_id(), Some(7));
+
+    assert_eq!(
+      Ipv6Multicast::from_rendezvous_point(
+        "2001:db8::1:f".parse().unwrap(),
+        64,
+        MulticastScope::Global,
+        1
+      ),
+      None
+    );
+    assert_eq!(
+      Ipv6Multicast::from_rendezvous_point(rp, 0, MulticastScope::Global, 1),
+      None
+    );
+  }
+
+  #[test]
+  fn solicited_node() {
+    for (ip, group) in [
+      ("2001:db8::1", "ff02::1:ff00:1"),
+      ("fe80::2aa:ff:fe28:9c5a", "ff02::1:ff28:9c5a"),
+      ("::", "ff02::1:ff00:0"),
+    ] {
+      let group = multicast(group);
+      assert_eq!(Ipv6Multicast::solicited_node(ip.parse().unwrap()), group);
+      assert!(group.is_solicited_node());
+      assert_eq!(
+        group.solicited_node_bits(),
+        Some(ip.parse::<Ipv6Addr>().unwrap().to_bits() as u32 & 0xff_ffff)
+      );
+    }
+    assert_eq!(multicast("ff02::1").solicited_node_bits(), None);
+    assert_eq!(multicast("ff05::1:ff00:1").solicited_node_bits(), None);
+  }
+
+  #[test]
+  fn ipv4() {
+    assert!(Ipv4Multicast::new(Ipv4Addr::new(192, 0, 2, 1)).is_none());
+    for (ip, block, scope, routable) in [
+      (
+        "224.0.0.1",
+        Ipv4MulticastBlock::LocalNetworkControl,
+        MulticastScope::LinkLocal,
+        false,
+      ),
+      (
+        "224.0.1.1",
+        Ipv4MulticastBlock::InternetworkControl,
+        MulticastScope::Global,
+        true,
+      ),
+      (
+        "224.2.127.254",
+        Ipv4MulticastBlock::Other,
+        MulticastScope::Global,
+        true,
+      ),
+      (
+        "232.1.2.3",
+        Ipv4MulticastBlock::SourceSpecific,
+        MulticastScope::Global,
+        true,
+      ),
+      (
+        "233.1.2.3",
+        Ipv4MulticastBlock::Glop,
+        MulticastScope::Global,
+        true,
+      ),
+      (
+        "233.252.0.1",
+        Ipv4MulticastBlock::Other,
+        MulticastScope::Global,
+        true,
+      ),
+      (
+        "239.255.255.250",
+        Ipv4MulticastBlock::AdministrativelyScoped,
+        MulticastScope::SiteLocal,
+        true,
+      ),
+      (
+        "239.195.1.1",
+        Ipv4MulticastBlock::AdministrativelyScoped,
+        MulticastScope::OrganizationLocal,
+        true,
+      ),
+      (
+        "239.1.1.1",
+        Ipv4MulticastBlock::AdministrativelyScoped,
+        MulticastScope::AdminLocal,
+        true,
+      ),
+    ] {
+      let group = Ipv4Multicast::new(ip.parse().unwrap()).unwrap();
+      assert_eq!(group.block(), block, "{ip}");
+      assert_eq!(group.scope(), scope, "{ip}");
+      assert_eq!(group.is_routable(), routable, "{ip}");
+    }
+
+    // RFC 3180 §2: AS 5662 maps to 233.22.30.0/24
+    let group = Ipv4Multicast::glop(5662, 7).unwrap();
+    assert_eq!(group.addr(), Ipv4Addr::new(233, 22, 30, 7));
+    assert_eq!(group.glop_asn(), Some(5662));
+    assert_eq!(Ipv4Multicast::glop(64512, 0), None);
+    assert_eq!(
+      Ipv4Multicast::glop(0xfbff, 1).unwrap().addr(),
+      Ipv4Addr::new(233, 251, 255, 1)
+    );
+    assert_eq!(Ipv4Multicast::glop(0xfc00, 1), None);
+    assert!(Ipv4Multicast::new(Ipv4Addr::new(232, 0, 0, 1))
+      .unwrap()
+      .is_source_specific());
+  }
+}
@@ -0,0 +1,179 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::MulticastScope::{self, Global, InterfaceLocal, LinkLocal, SiteLocal};
+
+/// A well-known multicast group address.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{MulticastGroups, MulticastScope};
+/// use std::net::Ipv4Addr;
+///
+/// let group = MulticastGroups::get(Ipv4Addr::new(224, 0, 0, 251).into()).unwrap();
+/// assert_eq!("mDNS", group.name());
+/// assert_eq!(6762, group.rfc());
+/// assert_eq!(MulticastScope::LinkLocal, group.scope());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct MulticastGroup {
+  addr: IpAddr,
+  name: &'static str,
+  rfc: u32,
+  scope: MulticastScope,
+}
+
+impl MulticastGroup {
+  /// Returns the group address.
+  #[inline]
+  pub const fn addr(&self) -> IpAddr {
+    self.addr
+  }
+
+  /// Returns the name of the group.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Returns the RFC defining the group.
+  #[inline]
+  pub const fn rfc(&self) -> u32 {
+    self.rfc
+  }
+
+  /// Returns the scope of the group.
+  #[inline]
+  pub const fn scope(&self) -> MulticastScope {
+    self.scope
+  }
+}
+
+const fn v4(addr: [u8; 4], name: &'static str, rfc: u32, scope: MulticastScope) -> MulticastGroup {
+  let [a, b, c, d] = addr;
+  MulticastGroup {
+    addr: IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+    name,
+    rfc,
+    scope,
+  }
+}
+
+const fn v6(scope: MulticastScope, group: u128, name: &'static str, rfc: u32) -> MulticastGroup {
+  let bits = (0xff0 << 116) | ((scope.value() as u128) << 112) | group;
+  MulticastGroup {
+    addr: IpAddr::V6(Ipv6Addr::from_bits(bits)),
+    name,
+    rfc,
+    scope,
+  }
+}
+
+const GROUPS: &[MulticastGroup] = &[
+  v4([224, 0, 0, 1], "All-Systems", 1112, LinkLocal),
+  v4([224, 0, 0, 2], "All-Routers", 2236, LinkLocal),
+  v4([224, 0, 0, 4], "DVMRP-Routers", 1075, LinkLocal),
+  v4([224, 0, 0, 5], "AllSPFRouters", 2328, LinkLocal),
+  v4([224, 0, 0, 6], "AllDRouters", 2328, LinkLocal),
+  v4([224, 0, 0, 9], "RIP2-Routers", 2453, LinkLocal),
+  v4([224, 0, 0, 10], "EIGRP-Routers", 7868, LinkLocal),
+  v4([224, 0, 0, 13], "ALL-PIM-ROUTERS", 7761, LinkLocal),
+  v4([224, 0, 0, 18], "VRRP", 9568, LinkLocal),
+  v4([224, 0, 0, 22], "IGMP", 3376, LinkLocal),
+  v4([224, 0, 0, 251], "mDNS", 6762, LinkLocal),
+  v4([224, 0, 0, 252], "LLMNR", 4795, LinkLocal),
+  v4([224, 0, 1, 1], "NTP", 5905, Global),
+  v6(InterfaceLocal, 0x1, "All-Nodes", 4291),
+  v6(LinkLocal, 0x1, "All-Nodes", 4291),
+  v6(InterfaceLocal, 0x2, "All-Routers", 4291),
+  v6(LinkLocal, 0x2, "All-Routers", 4291),
+  v6(SiteLocal, 0x2, "All-Routers", 4291),
+  v6(LinkLocal, 0x4, "DVMRP-Routers", 1075),
+  v6(LinkLocal, 0x5, "AllSPFRouters", 5340),
+  v6(LinkLocal, 0x6, "AllDRouters", 5340),
+  v6(LinkLocal, 0x9, "RIPng-Routers", 2080),
+  v6(LinkLocal, 0xa, "EIGRP-Routers", 7868),
+  v6(LinkLocal, 0xd, "ALL-PIM-ROUTERS", 7761),
+  v6(LinkLocal, 0x12, "VRRP", 9568),
+  v6(LinkLocal, 0x16, "All-MLDv2-Routers", 3810),
+  v6(LinkLocal, 0x1a, "All-RPL-Nodes", 6550),
+  v6(LinkLocal, 0xfb, "mDNS", 6762),
+  v6(LinkLocal, 0x1_0002, "All-DHCP-Agents", 8415),
+  v6(LinkLocal, 0x1_0003, "LLMNR", 4795),
+  v6(SiteLocal, 0x1_0003, "All-DHCP-Servers", 8415),
+];
+
+/// The table of well-known multicast groups.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::MulticastGroups;
+/// use std::net::IpAddr;
+///
+/// let addrs: Vec<IpAddr> = MulticastGroups::by_name("all-routers").map(|g| g.addr()).collect();
+/// assert_eq!(4, addrs.len());
+/// assert!(addrs.contains(&"ff02::2".parse().unwrap()));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct MulticastGroups;
+
+impl MulticastGroups {
+  /// Returns all the well-known groups, IPv4 first.
+  #[inline]
+  pub const fn all() -> &'static [MulticastGroup] {
+    GROUPS
+  }
+
+  /// Returns the well-known group with the given address.
+  #[inline]
+  pub fn get(addr: IpAddr) -> Option<&'static MulticastGroup> {
+    GROUPS.iter().find(|group| group.addr == addr)
+  }
+
+  /// Returns the well-known groups with the given name, compared
+  /// case-insensitively. A name may be shared by IPv4 and IPv6 groups and by
+  /// several scopes.
+  #[inline]
+  pub fn by_name(name: &str) -> impl Iterator<Item = &'static MulticastGroup> + '_ {
+    GROUPS
+      .iter()
+      .filter(move |group| group.name.eq_ignore_ascii_case(name))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Ipv4Multicast, Ipv6Multicast};
+
+  #[test]
+  fn groups() {
+    for group in MulticastGroups::all() {
+      let scope = match group.addr {
+        IpAddr::V4(ip) => Ipv4Multicast::new(ip).unwrap().scope(),
+        IpAddr::V6(ip) => Ipv6Multicast::new(ip).unwrap().scope(),
+      };
+      assert_eq!(scope, group.scope, "{}", group.addr);
+      assert_eq!(MulticastGroups::get(group.addr), Some(group));
+    }
+
+    for (addr, name) in [
+      ("224.0.0.251", "mDNS"),
+      ("ff02::fb", "mDNS"),
+      ("224.0.0.5", "AllSPFRouters"),
+      ("224.0.0.6", "AllDRouters"),
+      ("ff02::1:2", "All-DHCP-Agents"),
+      ("ff02::1", "All-Nodes"),
+      ("ff02::2", "All-Routers"),
+    ] {
+      let addr: IpAddr = addr.parse().unwrap();
+      assert_eq!(MulticastGroups::get(addr).unwrap().name(), name);
+      assert!(MulticastGroups::by_name(name).any(|g| g.addr() == addr));
+    }
+
+    assert_eq!(MulticastGroups::by_name("MDNS").count(), 2);
+    assert_eq!(MulticastGroups::by_name("unknown").count(), 0);
+    assert!(MulticastGroups::get("224.0.0.3".parse().unwrap()).is_none());
+  }
+}
@@ -0,0 +1,272 @@
+use core::{
+  fmt,
+  net::{Ipv4Addr, Ipv6Addr},
+};
+
+use ipnet::Ipv6Net;
+
+use super::{is_multicast_ipv4_addr, prefix_mask, RFCs};
+
+/// An error returned when building a [`Nat64Prefix`] or embedding an IPv4
+/// address with it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Nat64Error {
+  /// The prefix length is not one of 32, 40, 48, 56, 64 or 96
+  /// ([RFC 6052 §2.2](https://datatracker.ietf.org/doc/html/rfc6052#section-2.2)).
+  PrefixLength(u8),
+  /// Bits 64 to 71 of a /96 prefix, the u-octet, are not zero.
+  UOctet,
+  /// The IPv4 address is not global and cannot be embedded with the
+  /// Well-Known Prefix
+  /// ([RFC 6052 §3.1](https://datatracker.ietf.org/doc/html/rfc6052#section-3.1)).
+  NonGlobal(Ipv4Addr),
+}
+
+impl fmt::Display for Nat64Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::PrefixLength(len) => write!(f, "invalid IPv4-embedded IPv6 prefix length /{len}"),
+      Self::UOctet => write!(f, "bits 64 to 71 of the prefix must be zero"),
+      Self::NonGlobal(ip) => write!(f, "{ip} is not global and cannot use the Well-Known Prefix"),
+    }
+  }
+}
+
+impl core::error::Error for Nat64Error {}
+
+/// An [RFC 6052] IPv4/IPv6 translation prefix, either the Well-Known Prefix
+/// `64:ff9b::/96` or a Network-Specific Prefix.
+///
+/// The IPv4 address is placed right after the prefix, skipping bits 64 to
+/// 71 (the u-octet), and the suffix is zero.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::Nat64Prefix;
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+///
+/// let prefix = Nat64Prefix::new("2001:db8:100::/40".parse().unwrap()).unwrap();
+/// let ip = prefix.embed(Ipv4Addr::new(192, 0, 2, 33));
+/// assert_eq!("2001:db8:1c0:2:21::".parse::<Ipv6Addr>().unwrap(), ip);
+/// assert_eq!(Some(Ipv4Addr::new(192, 0, 2, 33)), prefix.extract(ip));
+/// ```
+///
+/// [RFC 6052]: https://datatracker.ietf.org/doc/rfc6052/
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Nat64Prefix(Ipv6Net);
+
+impl Nat64Prefix {
+  /// The Well-Known Prefix `64:ff9b::/96`.
+  pub const WELL_KNOWN: Self = Self(Ipv6Net::new_assert(
+    Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0, 0),
+    96,
+  ));
+
+  /// Creates a prefix from the network, ignoring its host bits.
+  pub const fn new(net: Ipv6Net) -> Result<Self, Nat64Error> {
+    let len = net.prefix_len();
+    match len {
+      32 | 40 | 48 | 56 | 64 | 96 => {}
+      _ => return Err(Nat64Error::PrefixLength(len)),
+    }
+
+    let bits = net.addr().to_bits() & prefix_mask(len);
+    if len == 96 && bits & U_OCTET != 0 {
+      return Err(Nat64Error::UOctet);
+    }
+    Ok(Self(Ipv6Net::new_assert(Ipv6Addr::from_bits(bits), len)))
+  }
+
+  /// Returns the prefix as a network.
+  #[inline]
+  pub const fn net(&self) -> Ipv6Net {
+    self.0
+  }
+
+  /// Returns `true` if this is the Well-Known Prefix.
+  #[inline]
+  pub const fn is_well_known(&self) -> bool {
+    self.0.addr().to_bits() == Self::WELL_KNOWN.0.addr().to_bits()
+      && self.0.prefix_len() == Self::WELL_KNOWN.0.prefix_len()
+  }
+
+  /// Returns the IPv6 address representing the IPv4 address.
+  pub const fn embed(&self, ip: Ipv4Addr) -> Ipv6Addr {
+    let mut octets = self.0.addr().octets();
+    let v4 = ip.octets();
+    let start = (self.0.prefix_len() / 8) as usize;
+    let mut i = 0;
+    while i < 4 {
+      octets[index(start, i)] = v4[i];
+      i += 1;
+    }
+    Ipv6Addr::from_bits(u128::from_be_bytes(octets))
+  }
+
+  /// Returns the IPv6 address representing the IPv4 address, after checking
+  /// it may be represented with this prefix.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Nat64Error, Nat64Prefix};
+  /// use std::net::Ipv4Addr;
+  ///
+  /// let private = Ipv4Addr::new(10, 1, 1, 1);
+  /// assert_eq!(
+  ///   Err(Nat64Error::NonGlobal(private)),
+  ///   Nat64Prefix::WELL_KNOWN.embed_checked(private)
+  /// );
+  /// ```
+  pub fn embed_checked(&self, ip: Ipv4Addr) -> Result<Ipv6Addr, Nat64Error> {
+    self.validate(ip).map(|_| self.embed(ip))
+  }
+
+  /// Returns the IPv4 address represented by the IPv6 address, or `None` if
+  /// the address is not within the prefix.
+  ///
+  /// The u-octet and the suffix are ignored.
+  pub const fn extract(&self, ip: Ipv6Addr) -> Option<Ipv4Addr> {
+    let len = self.0.prefix_len();
+    let mask = prefix_mask(len);
+    if ip.to_bits() & mask != self.0.addr().to_bits() {
+      return None;
+    }
+
+    let octets = ip.octets();
+    let start = (len / 8) as usize;
+    Some(Ipv4Addr::new(
+      octets[index(start, 0)],
+      octets[index(start, 1)],
+      octets[index(start, 2)],
+      octets[index(start, 3)],
+    ))
+  }
+
+  /// Checks that the IPv4 address may be represented with this prefix.
+  ///
+  /// The Well-Known Prefix must not represent non-global IPv4 addresses
+  /// ([RFC 6052 §3.1](https://datatracker.ietf.org/doc/html/rfc6052#section-3.1)),
+  /// i.e. multicast addresses and addresses of a special-purpose [`Block`](crate::Block)
+  /// that is not globally reachable. Network-Specific Prefixes accept any
+  /// address.
+  pub fn validate(&self, ip: Ipv4Addr) -> Result<(), Nat64Error> {
+    let non_global = is_multicast_ipv4_addr(ip)
+      || RFCs::block(&ip).is_some_and(|block| block.global() != Some(true));
+    if self.is_well_known() && non_global {
+      return Err(Nat64Error::NonGlobal(ip));
+    }
+    Ok(())
+  }
+}
+
+impl TryFrom<Ipv6Net> for Nat64Prefix {
+  type Error = Nat64Error;
+
+  #[inline]
+  fn try_from(net: Ipv6Net) -> Result<Self, Self::Error> {
+    Self::new(net)
+  }
+}
+
+impl From<Nat64Prefix> for Ipv6Net {
+  #[inline]
+  fn from(prefix: Nat64Prefix) -> Self {
+    prefix.0
+  }
+}
+
+/// Bits 64 to 71 of an IPv4-embedded IPv6 address.
+const U_OCTET: u128 = 0xff << 56;
+
+/// Returns the octet of the IPv6 address holding the `i`th octet of the IPv4
+/// address, for a prefix ending at octet `start`.
+#[inline]
+const fn index(start: usize, i: usize) -> usize {
+  let idx = start + i;
+  if start <= 8 && idx >= 8 {
+    idx + 1
+  } else {
+    idx
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rfc6052_examples() {
+    // RFC 6052 §2.4, Table 1
+    let ip = Ipv4Addr::new(192, 0, 2, 33);
+    for (prefix, expected) in [
+      ("2001:db8::/32", "2001:db8:c000:221::"),
+      ("2001:db8:100::/40", "2001:db8:1c0:2:21::"),
+      ("2001:db8:122::/48", "2001:db8:122:c000:2:2100::"),
+      ("2001:db8:122:300::/56", "2001:db8:122:3c0:0:221::"),
+      ("2001:db8:122:344::/64", "2001:db8:122:344:c0:2:2100:0"),
+      ("2001:db8:122:344::/96", "2001:db8:122:344::192.0.2.33"),
+      ("64:ff9b::/96", "64:ff9b::192.0.2.33"),
+    ] {
+      let prefix = Nat64Prefix::new(prefix.parse().unwrap()).unwrap();
+      let expected: Ipv6Addr = expected.parse().unwrap();
+      assert_eq!(prefix.embed(ip), expected, "{prefix:?}");
+      assert_eq!(prefix.extract(expected), Some(ip), "{prefix:?}");
+      assert_eq!(
+        prefix.embed_checked(ip).is_ok(),
+        !prefix.is_well_known(),
+        "{prefix:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn prefix() {
+    assert!(Nat64Prefix::new("64:ff9b::1/96".parse().unwrap())
+      .unwrap()
+      .is_well_known());
+    assert!(!Nat64Prefix::new("64:ff9b:1::/48".parse().unwrap())
+      .unwrap()
+      .is_well_known());
+    assert_eq!(
+      Nat64Prefix::new("2001:db8::/33".parse().unwrap()),
+      Err(Nat64Error::PrefixLength(33))
+    );
+    assert_eq!(
+      Nat64Prefix::new("2001:db8:0:0:100::/96".parse().unwrap()),
+      Err(Nat64Error::UOctet)
+    );
+    assert_eq!(
+      Nat64Prefix::WELL_KNOWN.extract("64:ff9c::1".parse().unwrap()),
+      None
+    );
+  }
+
+  #[test]
+  fn validate() {
+    let local = Nat64Prefix::new("64:ff9b:1::/48".parse().unwrap()).unwrap();
+    for ip in [
+      "10.0.0.1",
+      "100.64.0.1",
+      "127.0.0.1",
+      "192.0.2.1",
+      "224.0.0.1",
+      "240.0.0.1",
+    ] {
+      let ip: Ipv4Addr = ip.parse().unwrap();
+      assert_eq!(
+        Nat64Prefix::WELL_KNOWN.validate(ip),
+        Err(Nat64Error::NonGlobal(ip))
+      );
+      assert_eq!(local.validate(ip), Ok(()));
+    }
+
+    for ip in ["8.8.8.8", "192.0.0.9", "192.88.99.1"] {
+      assert_eq!(
+        Nat64Prefix::WELL_KNOWN.validate(ip.parse().unwrap()),
+        Ok(())
+      );
+    }
+  }
+}
@@ -0,0 +1,201 @@
+use core::{fmt, str::FromStr};
+
+use super::{Filter, RFCs, FORWARDING_BLACKLIST, RFC};
+
+/// An error returned when parsing an [`RFC`] or a [`Filter`] from a string.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ParseRfcError {
+  /// The input is empty or only contains whitespace.
+  Empty,
+  /// The input is neither an RFC number, optionally prefixed with `RFC`,
+  /// nor a name of the [`FORWARDING_BLACKLIST`], or the number does not fit
+  /// in a `u64`.
+  Malformed,
+  /// The input is a well-formed RFC number, but not a known RFC.
+  Unknown(u64),
+}
+
+impl fmt::Display for ParseRfcError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Empty => write!(f, "empty RFC identifier"),
+      Self::Malformed => write!(f, "malformed RFC identifier"),
+      Self::Unknown(id) => write!(f, "{id} is not a valid RFC identifier"),
+    }
+  }
+}
+
+impl core::error::Error for ParseRfcError {}
+
+/// The names accepted for the [`FORWARDING_BLACKLIST`], compared
+/// case-insensitively.
+const BLACKLIST_NAMES: &[&str] = &[
+  "blacklist",
+  "blocked",
+  "forwarding_blacklist",
+  "forwarding_blacklist_id",
+];
+
+/// Parses an RFC identifier such as `1918`, `RFC1918`, `rfc 1918` or
+/// `Rfc-1918`.
+pub(crate) fn parse(s: &str) -> Result<&'static RFC, ParseRfcError> {
+  let s = s.trim();
+  if s.is_empty() {
+    return Err(ParseRfcError::Empty);
+  }
+
+  if BLACKLIST_NAMES
+    .iter()
+    .any(|name| s.eq_ignore_ascii_case(name))
+  {
+    return Ok(&FORWARDING_BLACKLIST);
+  }
+
+  let digits = match s.get(..3) {
+    Some(prefix) if prefix.eq_ignore_ascii_case("rfc") => {
+      let rest = s[3..].trim_start();
+      rest.strip_prefix(['-', '_']).map_or(rest, str::trim_start)
+    }
+    _ => s,
+  };
+
+  if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+    return Err(ParseRfcError::Malformed);
+  }
+
+  let id = digits.parse().map_err(|_| ParseRfcError::Malformed)?;
+  u32::try_from(id)
+    .ok()
+    .and_then(RFCs::get)
+    .ok_or(ParseRfcError::Unknown(id))
+}
+
+impl FromStr for RFC {
+  type Err = ParseRfcError;
+
+  /// Parses an RFC identifier such as `1918`, `RFC1918`, `rfc 1918` or
+  /// `Rfc-1918`, or one of `blacklist`, `blocked` and `forwarding_blacklist`
+  /// for the [`FORWARDING_BLACKLIST`]. The prefix and names are
+  /// case-insensitive.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{ParseRfcError, RFC};
+  ///
+  /// let rfc: RFC = "Rfc-1918".parse().unwrap();
+  /// assert_eq!(1918, rfc.id());
+  ///
+  /// assert_eq!(Err(ParseRfcError::Unknown(9999)), "RFC 9999".parse::<RFC>());
+  /// assert_eq!(Err(ParseRfcError::Malformed), "RCF1918".parse::<RFC>());
+  /// assert_eq!(Err(ParseRfcError::Empty), " ".parse::<RFC>());
+  /// ```
+  #[inline]
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    parse(s).copied()
+  }
+}
+
+impl TryFrom<&str> for RFC {
+  type Error = ParseRfcError;
+
+  #[inline]
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    s.parse()
+  }
+}
+
+impl FromStr for Filter {
+  type Err = ParseRfcError;
+
+  /// Parses a list of RFC identifiers separated by `|` or `,`, such as
+  /// `RFC1918 | RFC6598`. Each identifier is parsed like [`RFC`]'s
+  /// [`FromStr`] implementation.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Filter, ParseRfcError};
+  ///
+  /// let filter: Filter = "RFC1918 | rfc 6598, blacklist".parse().unwrap();
+  /// assert_eq!(
+  ///   Filter::RFC1918 | Filter::RFC6598 | Filter::FORWARDING_BLACKLIST,
+  ///   filter
+  /// );
+  ///
+  /// assert_eq!(Err(ParseRfcError::Empty), "RFC1918 |".parse::<Filter>());
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    s.split(['|', ',']).try_fold(Filter::empty(), |filter, s| {
+      parse(s).map(|rfc| filter | rfc.filter())
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::FORWARDING_BLACKLIST_ID;
+
+  #[test]
+  fn parse_rfc() {
+    for s in [
+      "1918",
+      "RFC1918",
+      "rfc1918",
+      "Rfc-1918",
+      "RFC 1918",
+      "rfc_1918",
+      " RFC - 1918 ",
+    ] {
+      assert_eq!(s.parse::<RFC>().unwrap().id(), 1918, "{s}");
+      assert_eq!(RFC::try_from(s).unwrap().id(), 1918, "{s}");
+    }
+
+    for s in ["blacklist", "BLOCKED", "Forwarding_Blacklist", "4294967295"] {
+      assert_eq!(
+        s.parse::<RFC>().unwrap().id(),
+        FORWARDING_BLACKLIST_ID,
+        "{s}"
+      );
+    }
+
+    for (s, err) in [
+      ("", ParseRfcError::Empty),
+      ("  ", ParseRfcError::Empty),
+      ("RFC", ParseRfcError::Malformed),
+      ("RFC-", ParseRfcError::Malformed),
+      ("RCF1918", ParseRfcError::Malformed),
+      ("RFC--1918", ParseRfcError::Malformed),
+      ("RFC+1918", ParseRfcError::Malformed),
+      ("1918a", ParseRfcError::Malformed),
+      ("RFC99999999999", ParseRfcError::Unknown(99999999999)),
+      ("4294967296", ParseRfcError::Unknown(4294967296)),
+      ("18446744073709551615", ParseRfcError::Unknown(u64::MAX)),
+      ("RFC99999999999999999999", ParseRfcError::Malformed),
+      ("black list", ParseRfcError::Malformed),
+      ("9999", ParseRfcError::Unknown(9999)),
+      ("rfc 0", ParseRfcError::Unknown(0)),
+    ] {
+      assert_eq!(s.parse::<RFC>(), Err(err), "{s:?}");
+    }
+  }
+
+  #[test]
+  fn parse_filter() {
+    assert_eq!("RFC1918".parse::<Filter>(), Ok(Filter::RFC1918));
+    assert_eq!(
+      "rfc1918,RFC 4193 | blocked".parse::<Filter>(),
+      Ok(Filter::RFC1918 | Filter::RFC4193 | Filter::FORWARDING_BLACKLIST)
+    );
+    assert_eq!("".parse::<Filter>(), Err(ParseRfcError::Empty));
+    assert_eq!(
+      "RFC1918,,RFC4193".parse::<Filter>(),
+      Err(ParseRfcError::Empty)
+    );
+    assert_eq!(
+      "RFC1918 | RFC9999".parse::<Filter>(),
+      Err(ParseRfcError::Unknown(9999))
+    );
+  }
+}
@@ -0,0 +1,590 @@
+use core::{
+  fmt,
+  net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use alloc::vec::Vec;
+use ipnet::IpNet;
+
+use super::{
+  embedded::{embedded_ipv4, Embedding},
+  ipv6_net_contains, is_benchmark_ipv4_addr, is_benchmark_ipv6_addr, is_documentation_ipv4_addr,
+  is_documentation_ipv6_addr, is_link_local_ipv4_addr, is_link_local_ipv6_addr,
+  is_loopback_ipv4_addr, is_loopback_ipv6_addr, is_multicast_ipv4_addr, is_multicast_ipv6_addr,
+  is_private_ipv4_addr, is_shared_ipv4_addr, is_unique_local_ipv6_addr, is_unspecified_ipv6_addr,
+  rfc4291, Block, RFCs, RFC4380, RFC9637,
+};
+
+/// A category of address space that a [`DestinationPolicy`] denies by
+/// default.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Rule {
+  /// `127.0.0.0/8` and `::1/128`.
+  Loopback,
+  /// RFC 1918 private-use and RFC 4193 unique-local space.
+  Private,
+  /// `169.254.0.0/16` and `fe80::/10`.
+  LinkLocal,
+  /// RFC 6598 shared address space.
+  Shared,
+  /// RFC 5737, RFC 3849 and RFC 9637 documentation blocks.
+  Documentation,
+  /// RFC 2544 and RFC 5180 benchmarking blocks.
+  Benchmarking,
+  /// `224.0.0.0/4` and `ff00::/8`.
+  Multicast,
+  /// `0.0.0.0/8` and `::/128`.
+  Unspecified,
+  /// Space reserved by the protocol: `240.0.0.0/4`, the limited broadcast
+  /// address, `192.0.0.0/24`, the IPv4-mapped `::ffff:0:0/96`,
+  /// IPv4-compatible `::/96` and Teredo `2001::/32` prefixes, the `100::/64`
+  /// discard prefix, the deprecated `fec0::/10` site-local prefix, and any
+  /// other special-purpose [`Block`] that is not globally reachable.
+  Reserved,
+}
+
+impl Rule {
+  /// All the rules, in the order they are evaluated.
+  pub const ALL: [Rule; 9] = [
+    Rule::Unspecified,
+    Rule::Loopback,
+    Rule::Private,
+    Rule::LinkLocal,
+    Rule::Shared,
+    Rule::Documentation,
+    Rule::Benchmarking,
+    Rule::Multicast,
+    Rule::Reserved,
+  ];
+
+  /// Returns the name of the rule.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Loopback => "loopback",
+      Self::Private => "private",
+      Self::LinkLocal => "link-local",
+      Self::Shared => "shared",
+      Self::Documentation => "documentation",
+      Self::Benchmarking => "benchmarking",
+      Self::Multicast => "multicast",
+      Self::Unspecified => "unspecified",
+      Self::Reserved => "reserved",
+    }
+  }
+
+  /// Returns the rule matching the IPv4 address and the RFC defining the
+  /// matched block.
+  ///
+  /// Addresses in a special-purpose [`Block`] marked globally reachable, such
+  /// as `192.0.0.9/32`, match no rule.
+  pub fn of_ipv4(ip: Ipv4Addr) -> Option<(Rule, u32)> {
+    let block = RFCs::block(&ip);
+    Some(if ip.octets()[0] == 0 {
+      (Self::Unspecified, 1122)
+    } else if is_loopback_ipv4_addr(ip) {
+      (Self::Loopback, 1122)
+    } else if is_private_ipv4_addr(ip) {
+      (Self::Private, 1918)
+    } else if is_link_local_ipv4_addr(ip) {
+      (Self::LinkLocal, 3927)
+    } else if is_shared_ipv4_addr(ip) {
+      (Self::Shared, 6598)
+    } else if is_documentation_ipv4_addr(ip) {
+      (Self::Documentation, 5737)
+    } else if is_benchmark_ipv4_addr(ip) {
+      (Self::Benchmarking, 2544)
+    } else if is_multicast_ipv4_addr(ip) {
+      (Self::Multicast, 1112)
+    } else if let Some(&Block {
+      global: Some(false),
+      rfc,
+      ..
+    }) = block
+    {
+      (Self::Reserved, rfc)
+    } else {
+      return None;
+    })
+  }
+
+  /// Returns the rule matching the IPv6 address and the RFC defining the
+  /// matched block.
+  ///
+  /// IPv4 addresses embedded in the IPv6 address are not inspected, and
+  /// the IPv4-mapped, IPv4-compatible and Teredo prefixes match
+  /// [`Reserved`](Self::Reserved). Addresses in a special-purpose [`Block`]
+  /// marked globally reachable, such as `2001:1::1/128`, match no rule.
+  pub fn of_ipv6(ip: Ipv6Addr) -> Option<(Rule, u32)> {
+    let block = RFCs::block(&ip);
+    Some(if is_unspecified_ipv6_addr(ip) {
+      (Self::Unspecified, 4291)
+    } else if is_loopback_ipv6_addr(ip) {
+      (Self::Loopback, 4291)
+    } else if ipv6_net_contains(rfc4291::MAPPED, ip) || ipv6_net_contains(rfc4291::COMPATIBLE, ip) {
+      (Self::Reserved, 4291)
+    } else if RFC4380.contains_v6_const(ip) {
+      (Self::Reserved, 4380)
+    } else if is_unique_local_ipv6_addr(ip) {
+      (Self::Private, 4193)
+    } else if is_link_local_ipv6_addr(ip) {
+      (Self::LinkLocal, 4291)
+    } else if is_documentation_ipv6_addr(ip) {
+      if RFC9637.contains_v6_const(ip) {
+        (Self::Documentation, 9637)
+      } else {
+        (Self::Documentation, 3849)
+      }
+    } else if is_benchmark_ipv6_addr(ip) {
+      (Self::Benchmarking, 5180)
+    } else if is_multicast_ipv6_addr(ip) {
+      (Self::Multicast, 4291)
+    } else if ipv6_net_contains(rfc4291::SITE_LOCAL, ip) {
+      (Self::Reserved, 4291)
+    } else if let Some(&Block {
+      global: Some(false),
+      rfc,
+      ..
+    }) = block
+    {
+      (Self::Reserved, rfc)
+    } else {
+      return None;
+    })
+  }
+
+  #[inline]
+  const fn bit(self) -> u16 {
+    1 << self as u16
+  }
+}
+
+impl fmt::Display for Rule {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+/// Why a [`DestinationPolicy`] reached a [`Verdict`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Reason {
+  /// The address matched a [`Rule`], whose block is defined by the RFC.
+  Rule {
+    /// The matched rule.
+    rule: Rule,
+    /// The RFC defining the matched block.
+    rfc: u32,
+  },
+  /// The address matched an explicit allow or deny override.
+  Override(IpNet),
+  /// No rule or override matched the address.
+  Default,
+}
+
+/// The outcome of [`DestinationPolicy::check`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Verdict {
+  allowed: bool,
+  reason: Reason,
+  addr: IpAddr,
+  embedding: Option<Embedding>,
+}
+
+impl Verdict {
+  /// Returns `true` if the destination is allowed.
+  #[inline]
+  pub const fn is_allowed(&self) -> bool {
+    self.allowed
+  }
+
+  /// Returns `true` if the destination is denied.
+  #[inline]
+  pub const fn is_denied(&self) -> bool {
+    !self.allowed
+  }
+
+  /// Returns why the verdict was reached.
+  #[inline]
+  pub const fn reason(&self) -> Reason {
+    self.reason
+  }
+
+  /// Returns the rule that triggered the verdict, if any.
+  #[inline]
+  pub const fn rule(&self) -> Option<Rule> {
+    match self.reason {
+      Reason::Rule { rule, .. } => Some(rule),
+      _ => None,
+    }
+  }
+
+  /// Returns the RFC of the rule that triggered the verdict, if any.
+  #[inline]
+  pub const fn rfc(&self) -> Option<u32> {
+    match self.reason {
+      Reason::Rule { rfc, .. } => Some(rfc),
+      _ => None,
+    }
+  }
+
+  /// Returns the address the verdict was reached on.
+  ///
+  /// This is the embedded IPv4 address when the decision was made by
+  /// looking inside an IPv6 address.
+  #[inline]
+  pub const fn addr(&self) -> IpAddr {
+    self.addr
+  }
+
+  /// Returns how [`addr`](Self::addr) was embedded in the checked IPv6
+  /// address, if the verdict was reached on an embedded IPv4 address.
+  #[inline]
+  pub const fn embedding(&self) -> Option<Embedding> {
+    self.embedding
+  }
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let action = if self.allowed { "allow" } else { "deny" };
+    match self.reason {
+      Reason::Rule { rule, rfc } => write!(f, "{action} {}: {rule} (RFC {rfc})", self.addr),
+      Reason::Override(net) => write!(f, "{action} {}: override {net}", self.addr),
+      Reason::Default => write!(f, "{action} {}", self.addr),
+    }
+  }
+}
+
+/// A policy deciding whether a server may connect to a destination
+/// address, e.g. to guard outbound requests against SSRF.
+///
+/// By default every [`Rule`] is denied and every other address is allowed.
+/// Explicit overrides take precedence over the rules: the most specific
+/// override containing the address wins, and deny wins over allow for the
+/// same network.
+///
+/// IPv4 addresses embedded in IPv4-mapped, IPv4-compatible, NAT64, 6to4 and
+/// Teredo IPv6 addresses are checked as well, after the IPv6 address itself.
+/// The translation prefix then stands aside: an IPv6 address only
+/// [`Reserved`](Rule::Reserved) for its prefix, such as `::ffff:8.8.8.8`, is
+/// decided by its IPv4 address. When embedded addresses are not checked,
+/// these prefixes are denied as reserved. ISATAP interface identifiers are
+/// never unwrapped, as their IPv4 address is the one of the tunnel endpoint
+/// rather than the destination.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{DestinationPolicy, Rule};
+/// use std::net::IpAddr;
+///
+/// let policy = DestinationPolicy::new().allow("10.1.2.0/24".parse().unwrap());
+///
+/// let verdict = policy.check("64:ff9b::a00:1".parse::<IpAddr>().unwrap());
+/// assert!(verdict.is_denied());
+/// assert_eq!(Some(Rule::Private), verdict.rule());
+/// assert_eq!(Some(1918), verdict.rfc());
+/// assert_eq!("deny 10.0.0.1: private (RFC 1918)", verdict.to_string());
+///
+/// assert!(policy.is_allowed("10.1.2.3".parse::<IpAddr>().unwrap()));
+/// assert!(policy.is_allowed("93.184.215.14".parse::<IpAddr>().unwrap()));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DestinationPolicy {
+  denied: u16,
+  embedded: bool,
+  overrides: Vec<(IpNet, bool)>,
+}
+
+impl Default for DestinationPolicy {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl DestinationPolicy {
+  /// Creates a policy denying every [`Rule`].
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      denied: (1 << Rule::ALL.len()) - 1,
+      embedded: true,
+      overrides: Vec::new(),
+    }
+  }
+
+  /// Stops denying addresses matching the rule.
+  #[inline]
+  pub fn allow_rule(mut self, rule: Rule) -> Self {
+    self.denied &= !rule.bit();
+    self
+  }
+
+  /// Denies addresses matching the rule.
+  #[inline]
+  pub fn deny_rule(mut self, rule: Rule) -> Self {
+    self.denied |= rule.bit();
+    self
+  }
+
+  /// Allows the network, regardless of the rules.
+  #[inline]
+  pub fn allow(mut self, net: IpNet) -> Self {
+    self.overrides.push((net.trunc(), true));
+    self
+  }
+
+  /// Denies the network, regardless of the rules.
+  #[inline]
+  pub fn deny(mut self, net: IpNet) -> Self {
+    self.overrides.push((net.trunc(), false));
+    self
+  }
+
+  /// Sets whether IPv4 addresses embedded in IPv6 addresses are checked.
+  /// Defaults to `true`.
+  #[inline]
+  pub fn with_embedded(mut self, embedded: bool) -> Self {
+    self.embedded = embedded;
+    self
+  }
+
+  /// Returns `true` if the policy denies addresses matching the rule.
+  #[inline]
+  pub const fn denies(&self, rule: Rule) -> bool {
+    self.denied & rule.bit() != 0
+  }
+
+  /// Returns `true` if the policy allows the destination.
+  #[inline]
+  pub fn is_allowed(&self, ip: impl Into<IpAddr>) -> bool {
+    self.check(ip).is_allowed()
+  }
+
+  /// Checks the destination against the policy.
+  pub fn check(&self, ip: impl Into<IpAddr>) -> Verdict {
+    let ip = ip.into();
+    let embedded = match ip {
+      IpAddr::V6(v6) if self.embedded => {
+        embedded_ipv4(v6).filter(|(_, e)| !matches!(e, Embedding::Isatap))
+      }
+      _ => None,
+    };
+
+    match self.decide(ip, None) {
+      // the translation prefix is reserved, the embedded address decides
+      Some(verdict) if embedded.is_some() && verdict.rule() == Some(Rule::Reserved) => {}
+      Some(verdict) => return verdict,
+      None => {}
+    }
+
+    if let Some((v4, embedding)) = embedded {
+      if let Some(verdict) = self.decide(IpAddr::V4(v4), Some(embedding)) {
+        return verdict;
+      }
+    }
+
+    Verdict {
+      allowed: true,
+      reason: Reason::Default,
+      addr: ip,
+      embedding: None,
+    }
+  }
+
+  fn decide(&self, addr: IpAddr, embedding: Option<Embedding>) -> Option<Verdict> {
+    let overridden = self
+      .overrides
+      .iter()
+      .filter(|(net, _)| net.contains(&addr))
+      .max_by_key(|(net, allowed)| (net.prefix_len(), !allowed));
+    if let Some((net, allowed)) = overridden {
+      return Some(Verdict {
+        allowed: *allowed,
+        reason: Reason::Override(*net),
+        addr,
+        embedding,
+      });
+    }
+
+    let (rule, rfc) = match addr {
+      IpAddr::V4(ip) => Rule::of_ipv4(ip),
+      IpAddr::V6(ip) => Rule::of_ipv6(ip),
+    }?;
+    self.denies(rule).then_some(Verdict {
+      allowed: false,
+      reason: Reason::Rule { rule, rfc },
+      addr,
+      embedding,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  fn check(policy: &DestinationPolicy, s: &str) -> Verdict {
+    policy.check(s.parse::<IpAddr>().unwrap())
+  }
+
+  #[test]
+  fn default_rules() {
+    let policy = DestinationPolicy::new();
+    for (s, rule, rfc) in [
+      ("0.1.2.3", Rule::Unspecified, 1122),
+      ("::", Rule::Unspecified, 4291),
+      ("127.0.0.53", Rule::Loopback, 1122),
+      ("::1", Rule::Loopback, 4291),
+      ("172.31.255.255", Rule::Private, 1918),
+      ("fd12::1", Rule::Private, 4193),
+      ("169.254.169.254", Rule::LinkLocal, 3927),
+      ("fe80::1", Rule::LinkLocal, 4291),
+      ("100.100.100.200", Rule::Shared, 6598),
+      ("203.0.113.1", Rule::Documentation, 5737),
+      ("2001:db8::1", Rule::Documentation, 3849),
+      ("3fff::1", Rule::Documentation, 9637),
+      ("198.19.0.1", Rule::Benchmarking, 2544),
+      ("2001:2::1", Rule::Benchmarking, 5180),
+      ("239.1.1.1", Rule::Multicast, 1112),
+      ("ff02::1", Rule::Multicast, 4291),
+      ("255.255.255.255", Rule::Reserved, 919),
+      ("240.0.0.1", Rule::Reserved, 1112),
+      ("192.0.0.1", Rule::Reserved, 7335),
+      ("192.0.0.8", Rule::Reserved, 7600),
+      ("192.0.0.170", Rule::Reserved, 8880),
+      ("192.0.0.171", Rule::Reserved, 8880),
+      ("192.0.0.200", Rule::Reserved, 6890),
+      ("100::1", Rule::Reserved, 6666),
+      ("fec0::1", Rule::Reserved, 4291),
+      ("64:ff9b:1::a00:1", Rule::Reserved, 8215),
+      ("2001:5::1", Rule::Reserved, 2928),
+      ("2001:10::1", Rule::Reserved, 4843),
+      ("5f00::1", Rule::Reserved, 9602),
+    ] {
+      let verdict = check(&policy, s);
+      assert!(verdict.is_denied(), "{s}");
+      assert_eq!(verdict.reason(), Reason::Rule { rule, rfc }, "{s}");
+    }
+
+    for s in [
+      "8.8.8.8",
+      "2606:4700::1111",
+      "192.0.1.1",
+      "192.0.0.9",
+      "192.0.0.10",
+      "192.88.99.1",
+      "2001:1::1",
+      "2001:4:112::1",
+      "2001:20::1",
+    ] {
+      let verdict = check(&policy, s);
+      assert!(verdict.is_allowed(), "{s}");
+      assert_eq!(verdict.reason(), Reason::Default);
+    }
+  }
+
+  #[test]
+  fn embedded() {
+    let policy = DestinationPolicy::new();
+    for (s, v4, rule, embedding) in [
+      (
+        "::ffff:127.0.0.1",
+        "127.0.0.1",
+        Rule::Loopback,
+        Embedding::Mapped,
+      ),
+      (
+        "::10.0.0.1",
+        "10.0.0.1",
+        Rule::Private,
+        Embedding::Compatible,
+      ),
+      (
+        "64:ff9b::169.254.169.254",
+        "169.254.169.254",
+        Rule::LinkLocal,
+        Embedding::Nat64,
+      ),
+      (
+        "2002:c0a8:101::1",
+        "192.168.1.1",
+        Rule::Private,
+        Embedding::SixToFour,
+      ),
+      (
+        "2001:0:4136:e378:8000:63bf:80ff:fffe",
+        "127.0.0.1",
+        Rule::Loopback,
+        Embedding::Teredo,
+      ),
+    ] {
+      let verdict = check(&policy, s);
+      assert_eq!(verdict.rule(), Some(rule), "{s}");
+      assert_eq!(verdict.addr(), v4.parse::<IpAddr>().unwrap(), "{s}");
+      assert_eq!(verdict.embedding(), Some(embedding), "{s}");
+    }
+
+    assert!(check(&policy, "::ffff:8.8.8.8").is_allowed());
+    assert!(check(&policy, "2606:4700::5efe:7f00:1").is_allowed());
+    assert_eq!(check(&policy, "::1").embedding(), None);
+    assert!(check(&policy, "64:ff9b::808:808").is_allowed());
+    assert!(check(&policy, "2001:0:4136:e378:8000:63bf:f7f7:f7f7").is_allowed());
+
+    let policy = policy.with_embedded(false);
+    for s in ["::ffff:127.0.0.1", "::ffff:8.8.8.8", "::10.0.0.1"] {
+      let verdict = check(&policy, s);
+      assert!(verdict.is_denied(), "{s}");
+      assert_eq!(
+        verdict.reason(),
+        Reason::Rule {
+          rule: Rule::Reserved,
+          rfc: 4291
+        },
+        "{s}"
+      );
+      assert_eq!(verdict.embedding(), None, "{s}");
+    }
+    assert_eq!(
+      check(&policy, "2001:0:4136:e378:8000:63bf:80ff:fffe").reason(),
+      Reason::Rule {
+        rule: Rule::Reserved,
+        rfc: 4380
+      }
+    );
+  }
+
+  #[test]
+  fn overrides() {
+    let policy = DestinationPolicy::new()
+      .allow_rule(Rule::Documentation)
+      .allow("10.0.0.0/8".parse().unwrap())
+      .deny("10.9.0.0/16".parse().unwrap())
+      .allow("10.9.9.9/32".parse().unwrap())
+      .deny("8.8.8.8/32".parse().unwrap())
+      .allow("8.8.8.8/32".parse().unwrap());
+    assert!(!policy.denies(Rule::Documentation));
+    assert!(policy.denies(Rule::Private));
+
+    assert!(check(&policy, "192.0.2.1").is_allowed());
+    assert!(check(&policy, "10.1.1.1").is_allowed());
+    assert!(check(&policy, "10.9.1.1").is_denied());
+    assert!(check(&policy, "10.9.9.9").is_allowed());
+    assert!(check(&policy, "::ffff:10.9.9.9").is_allowed());
+    assert!(check(&policy, "192.168.0.1").is_denied());
+
+    let verdict = check(&policy, "8.8.8.8");
+    assert!(verdict.is_denied());
+    assert_eq!(
+      verdict.reason(),
+      Reason::Override("8.8.8.8/32".parse().unwrap())
+    );
+    assert_eq!(verdict.to_string(), "deny 8.8.8.8: override 8.8.8.8/32");
+
+    let policy = policy.deny_rule(Rule::Documentation);
+    assert!(check(&policy, "192.0.2.1").is_denied());
+  }
+}
@@ -0,0 +1,421 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use alloc::{string::String, vec::Vec};
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::{
+  sealed,
+  trie::{Key, ToKey, Trie},
+  Contains, Filter, Lookup, PrefixTrie,
+};
+
+/// A user-defined, named address block of a [`Registry`].
+///
+/// The attributes mirror the ones of a special-purpose [`Block`](crate::Block)
+/// and default to those of private-use space: valid as source and
+/// destination, forwardable, but not globally reachable.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::Entry;
+///
+/// let entry = Entry::new("vpn", ["10.8.0.0/16".parse().unwrap()]).with_forwardable(false);
+/// assert_eq!("vpn", entry.name());
+/// assert!(!entry.forwardable());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Entry {
+  name: String,
+  nets: Vec<IpNet>,
+  source: bool,
+  destination: bool,
+  forwardable: bool,
+  global: Option<bool>,
+  reserved_by_protocol: bool,
+}
+
+impl Entry {
+  /// Creates a new entry with the given name and networks.
+  pub fn new(name: impl Into<String>, nets: impl IntoIterator<Item = IpNet>) -> Self {
+    Self {
+      name: name.into(),
+      nets: nets.into_iter().map(|net| net.trunc()).collect(),
+      source: true,
+      destination: true,
+      forwardable: true,
+      global: Some(false),
+      reserved_by_protocol: false,
+    }
+  }
+
+  /// Sets whether an address of the entry is valid as a source address.
+  #[inline]
+  pub fn with_source(mut self, source: bool) -> Self {
+    self.source = source;
+    self
+  }
+
+  /// Sets whether an address of the entry is valid as a destination address.
+  #[inline]
+  pub fn with_destination(mut self, destination: bool) -> Self {
+    self.destination = destination;
+    self
+  }
+
+  /// Sets whether a router may forward datagrams destined to the entry.
+  #[inline]
+  pub fn with_forwardable(mut self, forwardable: bool) -> Self {
+    self.forwardable = forwardable;
+    self
+  }
+
+  /// Sets whether the entry is reachable beyond its administrative domain.
+  #[inline]
+  pub fn with_global(mut self, global: Option<bool>) -> Self {
+    self.global = global;
+    self
+  }
+
+  /// Sets whether the entry is reserved by the protocol itself.
+  #[inline]
+  pub fn with_reserved_by_protocol(mut self, reserved_by_protocol: bool) -> Self {
+    self.reserved_by_protocol = reserved_by_protocol;
+    self
+  }
+
+  /// Returns the name of the entry.
+  #[inline]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the networks of the entry.
+  #[inline]
+  pub fn nets(&self) -> &[IpNet] {
+    &self.nets
+  }
+
+  /// Returns `true` if an address of the entry is valid as a source address.
+  #[inline]
+  pub const fn source(&self) -> bool {
+    self.source
+  }
+
+  /// Returns `true` if an address of the entry is valid as a destination
+  /// address.
+  #[inline]
+  pub const fn destination(&self) -> bool {
+    self.destination
+  }
+
+  /// Returns `true` if a router may forward datagrams destined to the entry.
+  #[inline]
+  pub const fn forwardable(&self) -> bool {
+    self.forwardable
+  }
+
+  /// Returns `true` if the entry is reachable beyond its administrative
+  /// domain, `None` if not applicable.
+  #[inline]
+  pub const fn global(&self) -> Option<bool> {
+    self.global
+  }
+
+  /// Returns `true` if the entry is reserved by the protocol itself.
+  #[inline]
+  pub const fn reserved_by_protocol(&self) -> bool {
+    self.reserved_by_protocol
+  }
+}
+
+/// The result of [`Registry::classify`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Classification<'a> {
+  rfcs: Filter,
+  entries: Vec<&'a Entry>,
+}
+
+impl<'a> Classification<'a> {
+  /// Returns the built-in RFCs containing the ip.
+  #[inline]
+  pub const fn rfcs(&self) -> Filter {
+    self.rfcs
+  }
+
+  /// Returns the user-defined entries containing the ip, from the least to
+  /// the most specific one.
+  #[inline]
+  pub fn entries(&self) -> &[&'a Entry] {
+    &self.entries
+  }
+
+  /// Returns `true` if neither a built-in RFC nor an entry contains the ip.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.rfcs.is_empty() && self.entries.is_empty()
+  }
+}
+
+/// A runtime registry of the built-in RFCs and user-defined [`Entry`]s.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{Entry, Filter, Registry};
+/// use std::net::Ipv4Addr;
+///
+/// let mut registry = Registry::new();
+/// registry.insert(Entry::new("vpn", ["10.8.0.0/16".parse().unwrap()]));
+/// registry.insert(Entry::new("partner", ["203.0.114.0/24".parse().unwrap()]));
+///
+/// let class = registry.classify(&Ipv4Addr::new(10, 8, 1, 1));
+/// assert!(class.rfcs().contains(Filter::RFC1918));
+/// assert_eq!("vpn", class.entries()[0].name());
+///
+/// assert!(registry.contains(&Ipv4Addr::new(203, 0, 114, 7)));
+/// assert!(!registry.contains(&Ipv4Addr::new(8, 8, 8, 8)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Registry {
+  rfcs: PrefixTrie,
+  entries: Vec<Entry>,
+  trie: Trie<Vec<usize>>,
+}
+
+impl Default for Registry {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Registry {
+  /// Creates a registry of all known RFCs, without user-defined entries.
+  #[inline]
+  pub fn new() -> Self {
+    Self::with_trie(PrefixTrie::builtin())
+  }
+
+  /// Creates a registry of the RFCs in the given [`Filter`], without
+  /// user-defined entries.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Entry, Filter, Registry};
+  /// use std::net::Ipv4Addr;
+  ///
+  /// let mut registry = Registry::with_filter(Filter::empty());
+  /// assert!(!registry.contains(&Ipv4Addr::LOCALHOST));
+  ///
+  /// registry.insert(Entry::new("lab", ["192.168.50.0/24".parse().unwrap()]));
+  /// assert!(registry.contains(&Ipv4Addr::new(192, 168, 50, 1)));
+  /// assert!(!registry.contains(&Ipv4Addr::new(192, 168, 51, 1)));
+  /// ```
+  #[inline]
+  pub fn with_filter(filter: Filter) -> Self {
+    if filter == Filter::all() {
+      return Self::new();
+    }
+    Self::with_trie(PrefixTrie::new(filter))
+  }
+
+  #[inline]
+  fn with_trie(rfcs: PrefixTrie) -> Self {
+    Self {
+      rfcs,
+      entries: Vec::new(),
+      trie: Trie::new(),
+    }
+  }
+
+  /// Returns the built-in RFCs of the registry.
+  #[inline]
+  pub const fn filter(&self) -> Filter {
+    self.rfcs.filter()
+  }
+
+  /// Returns the user-defined entries of the registry, in insertion order.
+  #[inline]
+  pub fn entries(&self) -> &[Entry] {
+    &self.entries
+  }
+
+  /// Adds a user-defined entry to the registry.
+  pub fn insert(&mut self, entry: Entry) {
+    let idx = self.entries.len();
+    for net in entry.nets() {
+      let slot = self.trie.insert(*net);
+      if !slot.contains(&idx) {
+        slot.push(idx);
+      }
+    }
+    self.entries.push(entry);
+  }
+
+  /// Returns `true` if a built-in RFC or an entry of the registry contains
+  /// the ip.
+  #[inline]
+  pub fn contains<T>(&self, ip: &T) -> bool
+  where
+    Self: Contains<T>,
+  {
+    Contains::contains(self, ip)
+  }
+
+  /// Returns the built-in RFCs and the entries of the registry containing
+  /// the ip.
+  #[inline]
+  pub fn classify<T>(&self, ip: &T) -> Classification<'_>
+  where
+    Self: Classify<T>,
+  {
+    Classify::classify(self, ip)
+  }
+
+  /// Returns the most specific entry of the registry containing the ip.
+  ///
+  /// When several entries share the most specific network, the first
+  /// inserted one is returned.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Entry, Registry};
+  /// use std::net::Ipv4Addr;
+  ///
+  /// let mut registry = Registry::new();
+  /// registry.insert(Entry::new("corp", ["10.0.0.0/8".parse().unwrap()]));
+  /// registry.insert(Entry::new("lab", ["10.20.0.0/16".parse().unwrap()]));
+  ///
+  /// let entry = registry.entry(&Ipv4Addr::new(10, 20, 1, 1)).unwrap();
+  /// assert_eq!("lab", entry.name());
+  /// ```
+  #[inline]
+  pub fn entry<T>(&self, ip: &T) -> Option<&Entry>
+  where
+    Self: Classify<T>,
+  {
+    Classify::entry(self, ip)
+  }
+
+  fn classify_key(&self, key: Key) -> Vec<&Entry> {
+    let mut entries: Vec<&Entry> = Vec::new();
+    for idx in self.trie.path(key).flat_map(|(_, slot)| slot) {
+      let entry = &self.entries[*idx];
+      if !entries.iter().any(|e| core::ptr::eq(*e, entry)) {
+        entries.push(entry);
+      }
+    }
+    entries
+  }
+
+  fn entry_key(&self, key: Key) -> Option<&Entry> {
+    self
+      .trie
+      .path(key)
+      .filter_map(|(_, slot)| slot.first())
+      .last()
+      .map(|idx| &self.entries[*idx])
+  }
+}
+
+impl Extend<Entry> for Registry {
+  fn extend<I: IntoIterator<Item = Entry>>(&mut self, iter: I) {
+    iter.into_iter().for_each(|entry| self.insert(entry));
+  }
+}
+
+/// Classification queries of a [`Registry`].
+pub trait Classify<T>: sealed::Sealed {
+  /// Returns the built-in RFCs and the entries containing `T`.
+  fn classify(&self, t: &T) -> Classification<'_>;
+
+  /// Returns the most specific entry containing `T`.
+  fn entry(&self, t: &T) -> Option<&Entry>;
+}
+
+macro_rules! classify {
+  ($($ty:ty),+ $(,)?) => {
+    $(
+      impl Classify<$ty> for Registry {
+        #[inline]
+        fn classify(&self, t: &$ty) -> Classification<'_> {
+          Classification {
+            rfcs: Lookup::classify(&self.rfcs, t),
+            entries: self.classify_key(t.key()),
+          }
+        }
+
+        #[inline]
+        fn entry(&self, t: &$ty) -> Option<&Entry> {
+          self.entry_key(t.key())
+        }
+      }
+
+      impl Contains<$ty> for Registry {
+        #[inline]
+        fn contains(&self, t: &$ty) -> bool {
+          self.rfcs.contains(t) || self.trie.path(t.key()).any(|(_, slot)| !slot.is_empty())
+        }
+      }
+    )+
+  };
+}
+
+classify!(Ipv4Addr, Ipv6Addr, Ipv4Net, Ipv6Net, IpAddr, IpNet);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn net(s: &str) -> IpNet {
+    s.parse().unwrap()
+  }
+
+  fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+  }
+
+  #[test]
+  fn custom_entries() {
+    let mut registry = Registry::new();
+    registry.extend([
+      Entry::new("corp", [net("10.0.0.0/8"), net("fd00:1::/32")]),
+      Entry::new("vpn", [net("10.8.0.0/16")]).with_forwardable(false),
+      Entry::new("partner", [net("198.51.99.0/24"), net("198.51.99.128/25")]),
+    ]);
+    assert_eq!(registry.entries().len(), 3);
+
+    let class = registry.classify(&ip("10.8.1.1"));
+    assert!(class.rfcs().contains(Filter::RFC1918));
+    let names: Vec<_> = class.entries().iter().map(|e| e.name()).collect();
+    assert_eq!(names, ["corp", "vpn"]);
+    assert!(!registry.entry(&ip("10.8.1.1")).unwrap().forwardable());
+    assert_eq!(registry.entry(&ip("10.9.1.1")).unwrap().name(), "corp");
+
+    let class = registry.classify(&ip("198.51.99.200"));
+    assert!(class.rfcs().is_empty());
+    assert_eq!(class.entries().len(), 1);
+
+    assert!(registry.contains(&net("fd00:1:2::/48")));
+    assert!(registry.contains(&ip("::1")));
+    assert!(!registry.contains(&net("10.0.0.0/7")));
+    assert!(registry.classify(&ip("8.8.8.8")).is_empty());
+    assert!(registry.entry(&ip("192.168.1.1")).is_none());
+  }
+
+  #[test]
+  fn filtered_registry() {
+    let mut registry = Registry::with_filter(Filter::RFC1918);
+    assert_eq!(registry.filter(), Filter::RFC1918);
+    assert!(registry.contains(&ip("172.16.0.1")));
+    assert!(!registry.contains(&ip("127.0.0.1")));
+
+    registry.insert(Entry::new("loopback", [net("127.0.0.0/8")]));
+    let class = registry.classify(&ip("127.0.0.1"));
+    assert!(class.rfcs().is_empty());
+    assert_eq!(class.entries()[0].name(), "loopback");
+  }
+}
@@ -2,10 +2,25 @@ use core::net::Ipv4Addr;
 
 use ipnet::{IpNet, Ipv4Net};
 
-use super::RFC;
+use super::{Block, RFC};
 
-const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::UNSPECIFIED, 8);
-const IPV4_2: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(127, 0, 0, 0), 8);
+const THIS_NETWORK: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::UNSPECIFIED, 8);
+pub(crate) const THIS_HOST: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::UNSPECIFIED, 32);
+pub(crate) const LOOPBACK: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(127, 0, 0, 0), 8);
+
+const BLOCKS: &[Block] = &[Block {
+  net: IpNet::V4(THIS_HOST),
+  name: "This host on this network",
+  rfc: 1122,
+  section: Some("3.2.1.3"),
+  allocated: "1981-09",
+  terminated: None,
+  source: true,
+  destination: false,
+  forwardable: false,
+  global: Some(false),
+  reserved_by_protocol: true,
+}];
 
 /// [RFC 1122] Requirements for Internet Hosts -- Communication Layers
 ///
@@ -17,9 +32,10 @@ const IPV4_2: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(127, 0, 0, 0), 8);
 /// [RFC 1122]: https://datatracker.ietf.org/doc/rfc1122/
 pub const RFC1122: RFC = RFC {
   id: 1122,
-  ip_nets: &[IpNet::V4(IPV4_1), IpNet::V4(IPV4_2)],
-  ipv4_nets: &[IPV4_1, IPV4_2],
+  ip_nets: &[IpNet::V4(THIS_NETWORK), IpNet::V4(LOOPBACK)],
+  ipv4_nets: &[THIS_NETWORK, LOOPBACK],
   ipv6_nets: &[],
+  blocks: BLOCKS,
 };
 
 #[test]
@@ -28,4 +44,11 @@ fn t() {
     let addr: Ipv4Net = s.parse().unwrap();
     assert_eq!(RFC1122.ipv4_nets[idx], addr, "{s}");
   }
+
+  for block in RFC1122.blocks() {
+    assert!(RFC1122.contains(&block.net()));
+  }
+  let block = RFC1122.block(&Ipv4Addr::UNSPECIFIED).unwrap();
+  assert_eq!(block.name(), "This host on this network");
+  assert!(RFC1122.block(&Ipv4Addr::new(0, 0, 0, 1)).is_none());
 }
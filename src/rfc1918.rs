@@ -22,6 +22,7 @@ pub const RFC1918: RFC = RFC {
   ip_nets: &[IpNet::V4(IPV4_1), IpNet::V4(IPV4_2), IpNet::V4(IPV4_3)],
   ipv4_nets: &[IPV4_1, IPV4_2, IPV4_3],
   ipv6_nets: &[],
+  blocks: &[],
 };
 
 #[test]
@@ -0,0 +1,41 @@
+use core::net::Ipv4Addr;
+
+use ipnet::{IpNet, Ipv4Net};
+
+use super::RFC;
+
+/// 239.0.0.0/8
+pub(crate) const ADMINISTRATIVELY_SCOPED: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(239, 0, 0, 0), 8);
+
+/// 239.255.0.0/16
+pub(crate) const LOCAL_SCOPE: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(239, 255, 0, 0), 16);
+
+/// 239.192.0.0/14
+pub(crate) const ORGANIZATION_LOCAL_SCOPE: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(239, 192, 0, 0), 14);
+
+/// [RFC 2365] Administratively Scoped IP Multicast
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `239.0.0.0/8`: §6 Administratively Scoped IPv4 Multicast Space
+///   2. `239.255.0.0/16`: §6.1 IPv4 Local Scope
+///   3. `239.192.0.0/14`: §6.2 IPv4 Organization Local Scope
+///
+/// [RFC 2365]: https://datatracker.ietf.org/doc/rfc2365/
+pub const RFC2365: RFC = RFC {
+  id: 2365,
+  ip_nets: &[IpNet::V4(ADMINISTRATIVELY_SCOPED), IpNet::V4(LOCAL_SCOPE), IpNet::V4(ORGANIZATION_LOCAL_SCOPE)],
+  ipv4_nets: &[ADMINISTRATIVELY_SCOPED, LOCAL_SCOPE, ORGANIZATION_LOCAL_SCOPE],
+  ipv6_nets: &[],
+  blocks: &[],
+};
+
+#[test]
+fn t() {
+  let addr: Ipv4Net = "239.0.0.0/8".parse().unwrap();
+  assert_eq!(ADMINISTRATIVELY_SCOPED, addr);
+  let addr: Ipv4Net = "239.255.0.0/16".parse().unwrap();
+  assert_eq!(LOCAL_SCOPE, addr);
+  let addr: Ipv4Net = "239.192.0.0/14".parse().unwrap();
+  assert_eq!(ORGANIZATION_LOCAL_SCOPE, addr);
+}
@@ -18,6 +18,7 @@ pub const RFC2544: RFC = RFC {
   ip_nets: &[IpNet::V4(IPV4_1)],
   ipv4_nets: &[IPV4_1],
   ipv6_nets: &[],
+  blocks: &[],
 };
 
 #[test]
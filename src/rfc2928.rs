@@ -18,6 +18,7 @@ pub const RFC2928: RFC = RFC {
   ip_nets: &[IpNet::V6(IPV6_1)],
   ipv4_nets: &[],
   ipv6_nets: &[IPV6_1],
+  blocks: &[],
 };
 
 #[test]
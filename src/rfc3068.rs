@@ -20,6 +20,7 @@ pub const RFC3068: RFC = RFC {
   ip_nets: &[IpNet::V4(IPV4_1), IpNet::V6(IPV6_1)],
   ipv4_nets: &[IPV4_1],
   ipv6_nets: &[IPV6_1],
+  blocks: &[],
 };
 
 #[test]
@@ -0,0 +1,76 @@
+use core::net::Ipv4Addr;
+
+use ipnet::{IpNet, Ipv4Net};
+
+use super::RFC;
+
+/// 233.0.0.0/9
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(233, 0, 0, 0), 9);
+
+/// 233.128.0.0/10
+const IPV4_2: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(233, 128, 0, 0), 10);
+
+/// 233.192.0.0/11
+const IPV4_3: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(233, 192, 0, 0), 11);
+
+/// 233.224.0.0/12
+const IPV4_4: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(233, 224, 0, 0), 12);
+
+/// 233.240.0.0/13
+const IPV4_5: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(233, 240, 0, 0), 13);
+
+/// 233.248.0.0/14
+const IPV4_6: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(233, 248, 0, 0), 14);
+
+/// [RFC 3180] GLOP Addressing in 233/8
+///
+/// RFC 3180 §2 assigns all of `233.0.0.0/8`, but [RFC 5771 §10] reassigned
+/// `233.252.0.0/14` to AD-HOC Block III, so only `233.0.0.0` up to
+/// `233.251.255.255` is listed.
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `233.0.0.0/9`: §2 GLOP Addresses
+///   2. `233.128.0.0/10`: §2 GLOP Addresses
+///   3. `233.192.0.0/11`: §2 GLOP Addresses
+///   4. `233.224.0.0/12`: §2 GLOP Addresses
+///   5. `233.240.0.0/13`: §2 GLOP Addresses
+///   6. `233.248.0.0/14`: §2 GLOP Addresses
+///
+/// [RFC 3180]: https://datatracker.ietf.org/doc/rfc3180/
+/// [RFC 5771 §10]: https://datatracker.ietf.org/doc/html/rfc5771#section-10
+pub const RFC3180: RFC = RFC {
+  id: 3180,
+  ip_nets: &[
+    IpNet::V4(IPV4_1),
+    IpNet::V4(IPV4_2),
+    IpNet::V4(IPV4_3),
+    IpNet::V4(IPV4_4),
+    IpNet::V4(IPV4_5),
+    IpNet::V4(IPV4_6),
+  ],
+  ipv4_nets: &[IPV4_1, IPV4_2, IPV4_3, IPV4_4, IPV4_5, IPV4_6],
+  ipv6_nets: &[],
+  blocks: &[],
+};
+
+#[test]
+fn t() {
+  for (idx, s) in [
+    "233.0.0.0/9",
+    "233.128.0.0/10",
+    "233.192.0.0/11",
+    "233.224.0.0/12",
+    "233.240.0.0/13",
+    "233.248.0.0/14",
+  ]
+  .iter()
+  .enumerate()
+  {
+    let addr: Ipv4Net = s.parse().unwrap();
+    assert_eq!(RFC3180.ipv4_nets[idx], addr, "{s}");
+  }
+
+  assert!(RFC3180.contains(&Ipv4Addr::new(233, 251, 255, 255)));
+  assert!(!RFC3180.contains(&Ipv4Addr::new(233, 252, 0, 0)));
+}
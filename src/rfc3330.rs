@@ -117,6 +117,7 @@ pub const RFC3330: RFC = RFC {
     IPV4_1, IPV4_2, IPV4_3, IPV4_4, IPV4_5, IPV4_6, IPV4_7, IPV4_8, IPV4_9, IPV4_10, IPV4_11,
   ],
   ipv6_nets: &[],
+  blocks: &[],
 };
 
 #[test]
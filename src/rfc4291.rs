@@ -5,25 +5,25 @@ use ipnet::{IpNet, Ipv6Net};
 use super::RFC;
 
 /// ::/128
-const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::UNSPECIFIED, 128);
+pub(crate) const UNSPECIFIED: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::UNSPECIFIED, 128);
 
 /// ::1/128
-const IPV6_2: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::LOCALHOST, 128);
+pub(crate) const LOOPBACK: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::LOCALHOST, 128);
 
 /// ::/96
-const IPV6_3: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::UNSPECIFIED, 96);
+pub(crate) const COMPATIBLE: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::UNSPECIFIED, 96);
 
 /// ::ffff:0:0/96
-const IPV6_4: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0, 0, 0, 0, 0, 65535, 0, 0), 96);
+pub(crate) const MAPPED: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0, 0, 0, 0, 0, 65535, 0, 0), 96);
 
 /// fe80::/10
-const IPV6_5: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10);
+pub(crate) const LINK_LOCAL: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10);
 
 /// fec0::/10
-const IPV6_6: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfec0, 0, 0, 0, 0, 0, 0, 0), 10);
+pub(crate) const SITE_LOCAL: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfec0, 0, 0, 0, 0, 0, 0, 0), 10);
 
 /// ff00::/8
-const IPV6_7: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xff00, 0, 0, 0, 0, 0, 0, 0), 8);
+pub(crate) const MULTICAST: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xff00, 0, 0, 0, 0, 0, 0, 0), 8);
 
 /// [RFC 4291] IP Version 6 Addressing Architecture
 ///
@@ -41,16 +41,17 @@ const IPV6_7: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xff00, 0, 0, 0, 0, 0,
 pub const RFC4291: RFC = RFC {
   id: 4291,
   ip_nets: &[
-    IpNet::V6(IPV6_1),
-    IpNet::V6(IPV6_2),
-    IpNet::V6(IPV6_3),
-    IpNet::V6(IPV6_4),
-    IpNet::V6(IPV6_5),
-    IpNet::V6(IPV6_6),
-    IpNet::V6(IPV6_7),
+    IpNet::V6(UNSPECIFIED),
+    IpNet::V6(LOOPBACK),
+    IpNet::V6(COMPATIBLE),
+    IpNet::V6(MAPPED),
+    IpNet::V6(LINK_LOCAL),
+    IpNet::V6(SITE_LOCAL),
+    IpNet::V6(MULTICAST),
   ],
   ipv4_nets: &[],
-  ipv6_nets: &[IPV6_1, IPV6_2, IPV6_3, IPV6_4, IPV6_5, IPV6_6, IPV6_7],
+  ipv6_nets: &[UNSPECIFIED, LOOPBACK, COMPATIBLE, MAPPED, LINK_LOCAL, SITE_LOCAL, MULTICAST],
+  blocks: &[],
 };
 
 #[test]
@@ -13,12 +13,15 @@ const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0,
 /// - **IPv6:**
 ///   1. `2001:0000::/32`: §2.6 Global Teredo IPv6 Service Prefix
 ///
+/// See [`TeredoAddr`](crate::TeredoAddr) to decode Teredo addresses.
+///
 /// [RFC 4380]: https://datatracker.ietf.org/doc/rfc4380/
 pub const RFC4380: RFC = RFC {
   id: 4380,
   ip_nets: &[IpNet::V6(IPV6_1)],
   ipv4_nets: &[],
   ipv6_nets: &[IPV6_1],
+  blocks: &[],
 };
 
 #[test]
@@ -0,0 +1,29 @@
+use core::net::Ipv4Addr;
+
+use ipnet::{IpNet, Ipv4Net};
+
+use super::RFC;
+
+/// 232.0.0.0/8
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(232, 0, 0, 0), 8);
+
+/// [RFC 4607] Source-Specific Multicast for IP
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `232.0.0.0/8`: §1 Source-Specific Multicast Addresses
+///
+/// [RFC 4607]: https://datatracker.ietf.org/doc/rfc4607/
+pub const RFC4607: RFC = RFC {
+  id: 4607,
+  ip_nets: &[IpNet::V4(IPV4_1)],
+  ipv4_nets: &[IPV4_1],
+  ipv6_nets: &[],
+  blocks: &[],
+};
+
+#[test]
+fn t() {
+  let addr: Ipv4Net = "232.0.0.0/8".parse().unwrap();
+  assert_eq!(IPV4_1, addr);
+}
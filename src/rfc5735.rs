@@ -24,6 +24,7 @@ pub const RFC5735: RFC = RFC {
   ip_nets: &[IpNet::V4(IPV4_1), IpNet::V4(IPV4_2), IpNet::V4(IPV4_3), IpNet::V4(IPV4_4)],
   ipv4_nets: &[IPV4_1, IPV4_2, IPV4_3, IPV4_4],
   ipv6_nets: &[],
+  blocks: &[],
 };
 
 #[test]
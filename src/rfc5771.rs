@@ -0,0 +1,39 @@
+use core::net::Ipv4Addr;
+
+use ipnet::{IpNet, Ipv4Net};
+
+use super::RFC;
+
+/// 224.0.0.0/24
+pub(crate) const LOCAL_NETWORK_CONTROL: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(224, 0, 0, 0), 24);
+
+/// 224.0.1.0/24
+pub(crate) const INTERNETWORK_CONTROL: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(224, 0, 1, 0), 24);
+
+/// [RFC 5771] IANA Guidelines for IPv4 Multicast Address Assignments
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `224.0.0.0/24`: §4 Local Network Control Block
+///   2. `224.0.1.0/24`: §5 Internetwork Control Block
+///
+/// [`Ipv4Multicast`](crate::Ipv4Multicast) classifies multicast groups
+/// against these blocks and those of [`RFC4607`](crate::RFC4607),
+/// [`RFC3180`](crate::RFC3180) and [`RFC2365`](crate::RFC2365).
+///
+/// [RFC 5771]: https://datatracker.ietf.org/doc/rfc5771/
+pub const RFC5771: RFC = RFC {
+  id: 5771,
+  ip_nets: &[IpNet::V4(LOCAL_NETWORK_CONTROL), IpNet::V4(INTERNETWORK_CONTROL)],
+  ipv4_nets: &[LOCAL_NETWORK_CONTROL, INTERNETWORK_CONTROL],
+  ipv6_nets: &[],
+  blocks: &[],
+};
+
+#[test]
+fn t() {
+  let addr: Ipv4Net = "224.0.0.0/24".parse().unwrap();
+  assert_eq!(LOCAL_NETWORK_CONTROL, addr);
+  let addr: Ipv4Net = "224.0.1.0/24".parse().unwrap();
+  assert_eq!(INTERNETWORK_CONTROL, addr);
+}
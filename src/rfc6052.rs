@@ -13,12 +13,15 @@ const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0,
 /// - **IPv6:**
 ///   1. `64:ff9b::/96`: §2.1. Well-Known Prefix
 ///
+/// See [`Nat64Prefix`](crate::Nat64Prefix) to embed and extract IPv4 addresses.
+///
 /// [RFC 6052]: https://datatracker.ietf.org/doc/rfc6052/
 pub const RFC6052: RFC = RFC {
   id: 6052,
   ip_nets: &[IpNet::V6(IPV6_1)],
   ipv4_nets: &[],
   ipv6_nets: &[IPV6_1],
+  blocks: &[],
 };
 
 #[test]
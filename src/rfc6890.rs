@@ -2,86 +2,474 @@ use core::net::{Ipv4Addr, Ipv6Addr};
 
 use ipnet::{IpNet, Ipv4Net, Ipv6Net};
 
-use super::RFC;
+use super::{Block, RFC};
 
 /// 0.0.0.0/8
-const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(0, 0, 0, 0), 8);
+const THIS_NETWORK: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(0, 0, 0, 0), 8);
 
 /// 10.0.0.0/8
-const IPV4_2: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(10, 0, 0, 0), 8);
+const PRIVATE_10: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(10, 0, 0, 0), 8);
 
-/// 100.64.0.1/10
-const IPV4_3: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(100, 64, 0, 0), 10);
+/// 100.64.0.0/10
+const SHARED: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(100, 64, 0, 0), 10);
 
 /// 127.0.0.0/8
-const IPV4_4: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(127, 0, 0, 0), 8);
+const LOOPBACK_V4: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(127, 0, 0, 0), 8);
 
 /// 169.254.0.0/16
-const IPV4_5: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(169, 254, 0, 0), 16);
+const LINK_LOCAL_V4: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(169, 254, 0, 0), 16);
 
 /// 172.16.0.0/12
-const IPV4_6: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(172, 16, 0, 0), 12);
+const PRIVATE_172: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(172, 16, 0, 0), 12);
 
 /// 192.0.0.0/24
-const IPV4_7: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 0), 24);
+const IETF_PROTOCOL_V4: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 0), 24);
 
 /// 192.0.0.0/29
-const IPV4_8: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 0), 29);
+const SERVICE_CONTINUITY: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 0), 29);
 
 /// 192.0.2.0/24
-const IPV4_9: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 2, 0), 24);
+const TEST_NET_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 2, 0), 24);
 
 /// 192.88.99.0/24
-const IPV4_10: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 88, 99, 0), 24);
+const SIX_TO_FOUR_RELAY: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 88, 99, 0), 24);
 
 /// 192.168.0.0/16
-const IPV4_11: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 168, 0, 0), 16);
+const PRIVATE_192: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 168, 0, 0), 16);
 
-/// 192.18.0.0/15
-const IPV4_12: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(198, 18, 0, 0), 15);
+/// 198.18.0.0/15
+const BENCHMARKING_V4: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(198, 18, 0, 0), 15);
 
 /// 198.51.100.0/24
-const IPV4_13: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(198, 51, 100, 0), 24);
+const TEST_NET_2: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(198, 51, 100, 0), 24);
 
 /// 203.0.113.0/24
-const IPV4_14: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(203, 0, 113, 0), 24);
+const TEST_NET_3: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(203, 0, 113, 0), 24);
 
 /// 240.0.0.0/4
-const IPV4_15: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(240, 0, 0, 0), 4);
+pub(crate) const RESERVED: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(240, 0, 0, 0), 4);
 
 /// 255.255.255.255/32
-const IPV4_16: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::BROADCAST, 32);
+const BROADCAST: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::BROADCAST, 32);
 
 /// ::/128
-const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::UNSPECIFIED, 128);
+const UNSPECIFIED: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::UNSPECIFIED, 128);
 
 /// ::1/128
-const IPV6_2: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::LOCALHOST, 128);
+const LOOPBACK_V6: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::LOCALHOST, 128);
 
 /// 64:ff9b::/96
-const IPV6_3: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0, 0), 96);
+const TRANSLATION: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0, 0), 96);
 
 /// ::ffff:0:0/96
-const IPV6_4: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0, 0, 0, 0, 0, 65535, 0, 0), 96);
+const MAPPED: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0, 0, 0, 0, 0, 65535, 0, 0), 96);
 
 /// 100::/64
-const IPV6_5: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x0100, 0, 0, 0, 0, 0, 0, 0), 64);
+const DISCARD: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x0100, 0, 0, 0, 0, 0, 0, 0), 64);
 
 /// 2001::/23
-const IPV6_6: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 23);
+const IETF_PROTOCOL_V6: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 23);
 
 /// 2001:db8::/32
-const IPV6_7: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 0), 32);
+const DOCUMENTATION: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 0), 32);
 
 /// 2002::/16
-const IPV6_8: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2002, 0, 0, 0, 0, 0, 0, 0), 16);
+const SIX_TO_FOUR: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2002, 0, 0, 0, 0, 0, 0, 0), 16);
 
 /// fc00::/7
-const IPV6_9: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0), 7);
+const UNIQUE_LOCAL: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0), 7);
 
 /// fe80::/10
-const IPV6_10: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10);
-
+const LINK_LOCAL_V6: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10);
+
+/// 2001::/32
+const TEREDO: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 32);
+
+/// 2001:2::/48
+const BENCHMARKING_V6: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x0002, 0, 0, 0, 0, 0, 0), 48);
+
+/// 2001:10::/28
+const ORCHID: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x0010, 0, 0, 0, 0, 0, 0), 28);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V4(THIS_NETWORK),
+    name: "This network",
+    rfc: 791,
+    section: Some("3.2"),
+    allocated: "1981-09",
+    terminated: None,
+    source: true,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V4(PRIVATE_10),
+    name: "Private-Use",
+    rfc: 1918,
+    section: None,
+    allocated: "1996-02",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(SHARED),
+    name: "Shared Address Space",
+    rfc: 6598,
+    section: None,
+    allocated: "2012-04",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(LOOPBACK_V4),
+    name: "Loopback",
+    rfc: 1122,
+    section: Some("3.2.1.3"),
+    allocated: "1981-09",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V4(LINK_LOCAL_V4),
+    name: "Link Local",
+    rfc: 3927,
+    section: None,
+    allocated: "2005-05",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V4(PRIVATE_172),
+    name: "Private-Use",
+    rfc: 1918,
+    section: None,
+    allocated: "1996-02",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(IETF_PROTOCOL_V4),
+    name: "IETF Protocol Assignments",
+    rfc: 6890,
+    section: Some("2.1"),
+    allocated: "2010-01",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(SERVICE_CONTINUITY),
+    name: "IPv4 Service Continuity Prefix",
+    rfc: 7335,
+    section: None,
+    allocated: "2011-06",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(TEST_NET_1),
+    name: "Documentation (TEST-NET-1)",
+    rfc: 5737,
+    section: None,
+    allocated: "2010-01",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(SIX_TO_FOUR_RELAY),
+    name: "6to4 Relay Anycast",
+    rfc: 3068,
+    section: None,
+    allocated: "2001-06",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(PRIVATE_192),
+    name: "Private-Use",
+    rfc: 1918,
+    section: None,
+    allocated: "1996-02",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(BENCHMARKING_V4),
+    name: "Benchmarking",
+    rfc: 2544,
+    section: None,
+    allocated: "1999-03",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(TEST_NET_2),
+    name: "Documentation (TEST-NET-2)",
+    rfc: 5737,
+    section: None,
+    allocated: "2010-01",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(TEST_NET_3),
+    name: "Documentation (TEST-NET-3)",
+    rfc: 5737,
+    section: None,
+    allocated: "2010-01",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V4(RESERVED),
+    name: "Reserved",
+    rfc: 1112,
+    section: Some("4"),
+    allocated: "1989-08",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V4(BROADCAST),
+    name: "Limited Broadcast",
+    rfc: 919,
+    section: Some("7"),
+    allocated: "1984-10",
+    terminated: None,
+    source: false,
+    destination: true,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V6(UNSPECIFIED),
+    name: "Unspecified Address",
+    rfc: 4291,
+    section: None,
+    allocated: "2006-02",
+    terminated: None,
+    source: true,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V6(LOOPBACK_V6),
+    name: "Loopback Address",
+    rfc: 4291,
+    section: None,
+    allocated: "2006-02",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V6(TRANSLATION),
+    name: "IPv4-IPv6 Translat.",
+    rfc: 6052,
+    section: None,
+    allocated: "2010-10",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(MAPPED),
+    name: "IPv4-mapped Address",
+    rfc: 4291,
+    section: None,
+    allocated: "2006-02",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V6(DISCARD),
+    name: "Discard-Only Address Block",
+    rfc: 6666,
+    section: None,
+    allocated: "2012-06",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(IETF_PROTOCOL_V6),
+    name: "IETF Protocol Assignments",
+    rfc: 2928,
+    section: None,
+    allocated: "2000-09",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(TEREDO),
+    name: "TEREDO",
+    rfc: 4380,
+    section: None,
+    allocated: "2006-01",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: None,
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(BENCHMARKING_V6),
+    name: "Benchmarking",
+    rfc: 5180,
+    section: None,
+    allocated: "2008-04",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(DOCUMENTATION),
+    name: "Documentation",
+    rfc: 3849,
+    section: None,
+    allocated: "2004-07",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(ORCHID),
+    name: "ORCHID",
+    rfc: 4843,
+    section: None,
+    allocated: "2007-03",
+    terminated: Some("2014-03"),
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(SIX_TO_FOUR),
+    name: "6to4",
+    rfc: 3056,
+    section: None,
+    allocated: "2001-02",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: None,
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(UNIQUE_LOCAL),
+    name: "Unique-Local",
+    rfc: 4193,
+    section: None,
+    allocated: "2005-10",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(LINK_LOCAL_V6),
+    name: "Link-Local Unicast",
+    rfc: 4291,
+    section: None,
+    allocated: "2006-02",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+];
 
 /// [RFC 6890] Special-Purpose IP Address Registries
 ///
@@ -135,12 +523,17 @@ const IPV6_10: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0
 /// If the value of "Destination" is FALSE, the values of "Forwardable"
 /// and "Global" must also be false.
 ///
+/// The entries follow the current IANA registries, which have since split
+/// `0.0.0.0/32` out of `0.0.0.0/8` (see [`RFC1122`](crate::RFC1122)),
+/// renamed `fe80::/10` and updated Limited Broadcast and TEREDO through
+/// [RFC 8190].
+///
 /// **Addresses:**
 /// - **IPv4:**
 /// 
 ///   | Address Block | Name | RFC | Allocation Date | Termination Date | Source | Destination | Forwardable | Global | Reserved-by-Protocol |
 ///   |--------------|------|-----|-----------------|------------------|--------|-------------|-------------|--------|-------------------|
-///   | `0.0.0.0/8` | "This host on this network" | [RFC 1122], Section 3.2.1.3 | September 1981 | N/A | true | false | false | false | true |
+///   | `0.0.0.0/8` | "This network" | [RFC 791], Section 3.2 | September 1981 | N/A | true | false | false | false | true |
 ///   | `10.0.0.0/8` | Private-Use | [RFC 1918] | February 1996 | N/A | true | true | true | false | false |
 ///   | `100.64.0.0/10` | Shared Address Space | [RFC 6598] | April 2012 | N/A | true | true | true | false | false |
 ///   | `127.0.0.0/8` | Loopback¹ | [RFC 1122], Section 3.2.1.3 | September 1981 | N/A | false | false | false | false | true |
@@ -155,7 +548,7 @@ const IPV6_10: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0
 ///   | `198.51.100.0/24` | Documentation (TEST-NET-2) | [RFC 5737] | January 2010 | N/A | false | false | false | false | false |
 ///   | `203.0.113.0/24` | Documentation (TEST-NET-3) | [RFC 5737] | January 2010 | N/A | false | false | false | false | false |
 ///   | `240.0.0.0/4` | Reserved | [RFC 1112], Section 4 | August 1989 | N/A | false | false | false | false | true |
-///   | `255.255.255.255/32` | Limited Broadcast | [RFC 919], Section 7 | October 1984 | N/A | false | true | false | false | false |
+///   | `255.255.255.255/32` | Limited Broadcast | [RFC 8190], [RFC 919], Section 7 | October 1984 | N/A | false | true | false | false | true |
 /// 
 ///   ¹ Several protocols have been granted exceptions to this rule. For examples, see [RFC 4379] and [RFC 5884].
 /// 
@@ -171,18 +564,19 @@ const IPV6_10: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0
 ///   | `::ffff:0:0/96` | IPv4-mapped Address | [RFC 4291] | February 2006 | N/A | false | false | false | false | true |
 ///   | `100::/64` | Discard-Only Address Block | [RFC 6666] | June 2012 | N/A | true | true | true | false | false |
 ///   | `2001::/23`¹ | IETF Protocol Assignments | [RFC 2928] | September 2000 | N/A | false | false | false | false | false |
-///   | `2001::/32` | TEREDO | [RFC 4380] | January 2006 | N/A | true | true | true | false | false |
+///   | `2001::/32` | TEREDO | [RFC 4380], [RFC 8190] | January 2006 | N/A | true | true | true | N/A | false |
 ///   | `2001:2::/48` | Benchmarking | [RFC 5180] | April 2008 | N/A | true | true | true | false | false |
 ///   | `2001:db8::/32` | Documentation | [RFC 3849] | July 2004 | N/A | false | false | false | false | false |
 ///   | `2001:10::/28` | ORCHID | [RFC 4843] | March 2007 | March 2014 | false | false | false | false | false |
 ///   | `2002::/16`² | 6to4 | [RFC 3056] | February 2001 | N/A | true | true | true | N/A | false |
 ///   | `fc00::/7` | Unique-Local | [RFC 4193] | October 2005 | N/A | true | true | true | false | false |
-///   | `fe80::/10` | Linked-Scoped Unicast | [RFC 4291] | February 2006 | N/A | true | true | false | false | true |
+///   | `fe80::/10` | Link-Local Unicast | [RFC 4291] | February 2006 | N/A | true | true | false | false | true |
 /// 
 /// 
 ///   ¹ Unless allowed by a more specific allocation.  
 ///   ² See [RFC 3056] for details.
 /// 
+/// [RFC 791]: https://datatracker.ietf.org/doc/rfc791/
 /// [RFC 919]: https://datatracker.ietf.org/doc/rfc919/
 /// [RFC 1112]: https://datatracker.ietf.org/doc/rfc1112/
 /// [RFC 1122]: https://datatracker.ietf.org/doc/rfc1122/
@@ -207,52 +601,69 @@ const IPV6_10: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0
 /// [RFC 6598]: https://datatracker.ietf.org/doc/rfc6666/
 /// [RFC 6666]: https://datatracker.ietf.org/doc/rfc6666/
 /// [RFC 6890]: https://datatracker.ietf.org/doc/rfc6890/
+/// [RFC 8190]: https://datatracker.ietf.org/doc/rfc8190/
 pub const RFC6890: RFC = RFC {
   id: 6890,
   ip_nets: &[
-    IpNet::V4(IPV4_1),
-    IpNet::V4(IPV4_2),
-    IpNet::V4(IPV4_3),
-    IpNet::V4(IPV4_4),
-    IpNet::V4(IPV4_5),
-    IpNet::V4(IPV4_6),
-    IpNet::V4(IPV4_7),
-    IpNet::V4(IPV4_8),
-    IpNet::V4(IPV4_9),
-    IpNet::V4(IPV4_10),
-    IpNet::V4(IPV4_11),
-    IpNet::V4(IPV4_12),
-    IpNet::V4(IPV4_13),
-    IpNet::V4(IPV4_14),
-    IpNet::V4(IPV4_15),
-    IpNet::V4(IPV4_16),
-
-    IpNet::V6(IPV6_1),
-    IpNet::V6(IPV6_2),
-    IpNet::V6(IPV6_3),
-    IpNet::V6(IPV6_4),
-    IpNet::V6(IPV6_5),
-    IpNet::V6(IPV6_6),
-    IpNet::V6(IPV6_7),
-    IpNet::V6(IPV6_8),
-    IpNet::V6(IPV6_9),
-    IpNet::V6(IPV6_10),
+    IpNet::V4(THIS_NETWORK),
+    IpNet::V4(PRIVATE_10),
+    IpNet::V4(SHARED),
+    IpNet::V4(LOOPBACK_V4),
+    IpNet::V4(LINK_LOCAL_V4),
+    IpNet::V4(PRIVATE_172),
+    IpNet::V4(IETF_PROTOCOL_V4),
+    IpNet::V4(SERVICE_CONTINUITY),
+    IpNet::V4(TEST_NET_1),
+    IpNet::V4(SIX_TO_FOUR_RELAY),
+    IpNet::V4(PRIVATE_192),
+    IpNet::V4(BENCHMARKING_V4),
+    IpNet::V4(TEST_NET_2),
+    IpNet::V4(TEST_NET_3),
+    IpNet::V4(RESERVED),
+    IpNet::V4(BROADCAST),
+
+    IpNet::V6(UNSPECIFIED),
+    IpNet::V6(LOOPBACK_V6),
+    IpNet::V6(TRANSLATION),
+    IpNet::V6(MAPPED),
+    IpNet::V6(DISCARD),
+    IpNet::V6(IETF_PROTOCOL_V6),
+    IpNet::V6(DOCUMENTATION),
+    IpNet::V6(SIX_TO_FOUR),
+    IpNet::V6(UNIQUE_LOCAL),
+    IpNet::V6(LINK_LOCAL_V6),
   ],
   ipv4_nets: &[
-    IPV4_1, IPV4_2, IPV4_3, IPV4_4, IPV4_5, IPV4_6, IPV4_7, IPV4_8, IPV4_9, IPV4_10, IPV4_11, IPV4_12, IPV4_13, IPV4_14, IPV4_15, IPV4_16,
+    THIS_NETWORK,
+    PRIVATE_10,
+    SHARED,
+    LOOPBACK_V4,
+    LINK_LOCAL_V4,
+    PRIVATE_172,
+    IETF_PROTOCOL_V4,
+    SERVICE_CONTINUITY,
+    TEST_NET_1,
+    SIX_TO_FOUR_RELAY,
+    PRIVATE_192,
+    BENCHMARKING_V4,
+    TEST_NET_2,
+    TEST_NET_3,
+    RESERVED,
+    BROADCAST,
   ],
   ipv6_nets: &[
-    IPV6_1,
-    IPV6_2,
-    IPV6_3,
-    IPV6_4,
-    IPV6_5,
-    IPV6_6,
-    IPV6_7,
-    IPV6_8,
-    IPV6_9,
-    IPV6_10,
+    UNSPECIFIED,
+    LOOPBACK_V6,
+    TRANSLATION,
+    MAPPED,
+    DISCARD,
+    IETF_PROTOCOL_V6,
+    DOCUMENTATION,
+    SIX_TO_FOUR,
+    UNIQUE_LOCAL,
+    LINK_LOCAL_V6,
   ],
+  blocks: BLOCKS,
 };
 
 #[test]
@@ -319,3 +730,42 @@ fn ipv6_2001_block_is_not_wholesale_special() {
     assert!(RFC6890.contains(&ip), "{s} is special-purpose");
   }
 }
+
+#[test]
+fn blocks() {
+  use core::net::IpAddr;
+
+  for block in RFC6890.blocks() {
+    assert!(RFC6890.contains(&block.net()), "{}", block.net());
+    // RFC 6890 §2.2.1: if Destination is false, Forwardable and Global must be too.
+    if !block.destination() {
+      assert!(!block.forwardable(), "{}", block.net());
+      assert_ne!(block.global(), Some(true), "{}", block.net());
+    }
+  }
+
+  for (s, name, rfc) in [
+    ("0.0.0.1", "This network", 791),
+    ("192.0.0.1", "IPv4 Service Continuity Prefix", 7335),
+    ("192.0.0.9", "IETF Protocol Assignments", 6890),
+    ("255.255.255.255", "Limited Broadcast", 919),
+    ("2001::1", "TEREDO", 4380),
+    ("2001:2::1", "Benchmarking", 5180),
+    ("2001:10::1", "ORCHID", 4843),
+    ("2001:1::1", "IETF Protocol Assignments", 2928),
+    ("2002::1", "6to4", 3056),
+  ] {
+    let ip: IpAddr = s.parse().unwrap();
+    let block = RFC6890.block(&ip).unwrap();
+    assert_eq!(block.name(), name, "{s}");
+    assert_eq!(block.rfc(), rfc, "{s}");
+  }
+
+  for s in ["2001::1", "2002::1"] {
+    let ip: IpAddr = s.parse().unwrap();
+    assert_eq!(RFC6890.block(&ip).unwrap().global(), None, "{s}");
+  }
+
+  let ip: IpAddr = "2001:4860:4860::8888".parse().unwrap();
+  assert!(RFC6890.block(&ip).is_none());
+}
@@ -0,0 +1,49 @@
+use core::net::Ipv6Addr;
+
+use ipnet::{IpNet, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 2001:20::/28
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x20, 0, 0, 0, 0, 0, 0), 28);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "ORCHIDv2",
+    rfc: 7343,
+    section: None,
+    allocated: "2014-07",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 7343] An IPv6 Prefix for Overlay Routable Cryptographic Hash Identifiers Version 2 (ORCHIDv2)
+///
+/// **Addresses:**
+/// - **IPv6:**
+///   1. `2001:20::/28`: ORCHIDv2
+///
+/// [RFC 7343]: https://datatracker.ietf.org/doc/rfc7343/
+pub const RFC7343: RFC = RFC {
+  id: 7343,
+  ip_nets: &[IpNet::V6(IPV6_1)],
+  ipv4_nets: &[],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  let addr: Ipv6Net = "2001:20::/28".parse().unwrap();
+  assert_eq!(IPV6_1, addr);
+
+  for block in RFC7343.blocks() {
+    assert!(RFC7343.contains(&block.net()));
+  }
+}
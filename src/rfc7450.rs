@@ -0,0 +1,74 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 192.52.193.0/24
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 52, 193, 0), 24);
+
+/// 2001:3::/32
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x3, 0, 0, 0, 0, 0, 0), 32);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V4(IPV4_1),
+    name: "AMT",
+    rfc: 7450,
+    section: None,
+    allocated: "2014-12",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "AMT",
+    rfc: 7450,
+    section: None,
+    allocated: "2014-12",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 7450] Automatic Multicast Tunneling
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `192.52.193.0/24`: AMT
+/// - **IPv6:**
+///   1. `2001:3::/32`: AMT
+///
+/// [RFC 7450]: https://datatracker.ietf.org/doc/rfc7450/
+pub const RFC7450: RFC = RFC {
+  id: 7450,
+  ip_nets: &[IpNet::V4(IPV4_1), IpNet::V6(IPV6_1)],
+  ipv4_nets: &[IPV4_1],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  for (idx, s) in ["192.52.193.0/24"].iter().enumerate() {
+    let addr: Ipv4Net = s.parse().unwrap();
+    assert_eq!(RFC7450.ipv4_nets[idx], addr);
+  }
+
+  for (idx, s) in ["2001:3::/32"].iter().enumerate() {
+    let addr: Ipv6Net = s.parse().unwrap();
+    assert_eq!(RFC7450.ipv6_nets[idx], addr);
+  }
+
+  for block in RFC7450.blocks() {
+    assert!(RFC7450.contains(&block.net()));
+  }
+}
@@ -0,0 +1,74 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 192.175.48.0/24
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 175, 48, 0), 24);
+
+/// 2620:4f:8000::/48
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2620, 0x4f, 0x8000, 0, 0, 0, 0, 0), 48);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V4(IPV4_1),
+    name: "Direct Delegation AS112 Service",
+    rfc: 7534,
+    section: None,
+    allocated: "1996-01",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "Direct Delegation AS112 Service",
+    rfc: 7534,
+    section: None,
+    allocated: "2011-05",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 7534] AS112 Nameserver Operations
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `192.175.48.0/24`: Direct Delegation AS112 Service
+/// - **IPv6:**
+///   1. `2620:4f:8000::/48`: Direct Delegation AS112 Service
+///
+/// [RFC 7534]: https://datatracker.ietf.org/doc/rfc7534/
+pub const RFC7534: RFC = RFC {
+  id: 7534,
+  ip_nets: &[IpNet::V4(IPV4_1), IpNet::V6(IPV6_1)],
+  ipv4_nets: &[IPV4_1],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  for (idx, s) in ["192.175.48.0/24"].iter().enumerate() {
+    let addr: Ipv4Net = s.parse().unwrap();
+    assert_eq!(RFC7534.ipv4_nets[idx], addr);
+  }
+
+  for (idx, s) in ["2620:4f:8000::/48"].iter().enumerate() {
+    let addr: Ipv6Net = s.parse().unwrap();
+    assert_eq!(RFC7534.ipv6_nets[idx], addr);
+  }
+
+  for block in RFC7534.blocks() {
+    assert!(RFC7534.contains(&block.net()));
+  }
+}
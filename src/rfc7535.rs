@@ -0,0 +1,74 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 192.31.196.0/24
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 31, 196, 0), 24);
+
+/// 2001:4:112::/48
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x4, 0x112, 0, 0, 0, 0, 0), 48);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V4(IPV4_1),
+    name: "AS112-v4",
+    rfc: 7535,
+    section: None,
+    allocated: "2014-12",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "AS112-v6",
+    rfc: 7535,
+    section: None,
+    allocated: "2014-12",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 7535] AS112 Redirection Using DNAME
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `192.31.196.0/24`: AS112-v4
+/// - **IPv6:**
+///   1. `2001:4:112::/48`: AS112-v6
+///
+/// [RFC 7535]: https://datatracker.ietf.org/doc/rfc7535/
+pub const RFC7535: RFC = RFC {
+  id: 7535,
+  ip_nets: &[IpNet::V4(IPV4_1), IpNet::V6(IPV6_1)],
+  ipv4_nets: &[IPV4_1],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  for (idx, s) in ["192.31.196.0/24"].iter().enumerate() {
+    let addr: Ipv4Net = s.parse().unwrap();
+    assert_eq!(RFC7535.ipv4_nets[idx], addr);
+  }
+
+  for (idx, s) in ["2001:4:112::/48"].iter().enumerate() {
+    let addr: Ipv6Net = s.parse().unwrap();
+    assert_eq!(RFC7535.ipv6_nets[idx], addr);
+  }
+
+  for block in RFC7535.blocks() {
+    assert!(RFC7535.contains(&block.net()));
+  }
+}
@@ -0,0 +1,49 @@
+use core::net::Ipv4Addr;
+
+use ipnet::{IpNet, Ipv4Net};
+
+use super::{Block, RFC};
+
+/// 192.0.0.8/32
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 8), 32);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V4(IPV4_1),
+    name: "IPv4 dummy address",
+    rfc: 7600,
+    section: None,
+    allocated: "2015-03",
+    terminated: None,
+    source: true,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 7600] IPv4 Residual Deployment via IPv6 - A Stateless Solution (4rd)
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `192.0.0.8/32`: IPv4 dummy address
+///
+/// [RFC 7600]: https://datatracker.ietf.org/doc/rfc7600/
+pub const RFC7600: RFC = RFC {
+  id: 7600,
+  ip_nets: &[IpNet::V4(IPV4_1)],
+  ipv4_nets: &[IPV4_1],
+  ipv6_nets: &[],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  let addr: Ipv4Net = "192.0.0.8/32".parse().unwrap();
+  assert_eq!(IPV4_1, addr);
+
+  for block in RFC7600.blocks() {
+    assert!(RFC7600.contains(&block.net()));
+  }
+}
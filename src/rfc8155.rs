@@ -0,0 +1,74 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 192.0.0.10/32
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 10), 32);
+
+/// 2001:1::2/128
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x1, 0, 0, 0, 0, 0, 0x2), 128);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V4(IPV4_1),
+    name: "Traversal Using Relays around NAT Anycast",
+    rfc: 8155,
+    section: None,
+    allocated: "2017-02",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "Traversal Using Relays around NAT Anycast",
+    rfc: 8155,
+    section: None,
+    allocated: "2017-02",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 8155] Traversal Using Relays around NAT (TURN) Server Auto Discovery
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `192.0.0.10/32`: Traversal Using Relays around NAT Anycast
+/// - **IPv6:**
+///   1. `2001:1::2/128`: Traversal Using Relays around NAT Anycast
+///
+/// [RFC 8155]: https://datatracker.ietf.org/doc/rfc8155/
+pub const RFC8155: RFC = RFC {
+  id: 8155,
+  ip_nets: &[IpNet::V4(IPV4_1), IpNet::V6(IPV6_1)],
+  ipv4_nets: &[IPV4_1],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  for (idx, s) in ["192.0.0.10/32"].iter().enumerate() {
+    let addr: Ipv4Net = s.parse().unwrap();
+    assert_eq!(RFC8155.ipv4_nets[idx], addr);
+  }
+
+  for (idx, s) in ["2001:1::2/128"].iter().enumerate() {
+    let addr: Ipv6Net = s.parse().unwrap();
+    assert_eq!(RFC8155.ipv6_nets[idx], addr);
+  }
+
+  for block in RFC8155.blocks() {
+    assert!(RFC8155.contains(&block.net()));
+  }
+}
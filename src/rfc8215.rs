@@ -0,0 +1,49 @@
+use core::net::Ipv6Addr;
+
+use ipnet::{IpNet, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 64:ff9b:1::/48
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x64, 0xff9b, 0x1, 0, 0, 0, 0, 0), 48);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "IPv4-IPv6 Translat.",
+    rfc: 8215,
+    section: None,
+    allocated: "2017-06",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 8215] Local-Use IPv4/IPv6 Translation Prefix
+///
+/// **Addresses:**
+/// - **IPv6:**
+///   1. `64:ff9b:1::/48`: IPv4-IPv6 Translat.
+///
+/// [RFC 8215]: https://datatracker.ietf.org/doc/rfc8215/
+pub const RFC8215: RFC = RFC {
+  id: 8215,
+  ip_nets: &[IpNet::V6(IPV6_1)],
+  ipv4_nets: &[],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  let addr: Ipv6Net = "64:ff9b:1::/48".parse().unwrap();
+  assert_eq!(IPV6_1, addr);
+
+  for block in RFC8215.blocks() {
+    assert!(RFC8215.contains(&block.net()));
+  }
+}
@@ -0,0 +1,68 @@
+use core::net::Ipv4Addr;
+
+use ipnet::{IpNet, Ipv4Net};
+
+use super::{Block, RFC};
+
+/// 192.0.0.170/32
+const IPV4_1: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 170), 32);
+
+/// 192.0.0.171/32
+const IPV4_2: Ipv4Net = Ipv4Net::new_assert(Ipv4Addr::new(192, 0, 0, 171), 32);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V4(IPV4_1),
+    name: "NAT64/DNS64 Discovery",
+    rfc: 8880,
+    section: Some("2.2"),
+    allocated: "2013-02",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+  Block {
+    net: IpNet::V4(IPV4_2),
+    name: "NAT64/DNS64 Discovery",
+    rfc: 8880,
+    section: Some("2.2"),
+    allocated: "2013-02",
+    terminated: None,
+    source: false,
+    destination: false,
+    forwardable: false,
+    global: Some(false),
+    reserved_by_protocol: true,
+  },
+];
+
+/// [RFC 8880] Special Use Domain Name 'ipv4only.arpa'
+///
+/// **Addresses:**
+/// - **IPv4:**
+///   1. `192.0.0.170/32`: NAT64/DNS64 Discovery
+///   2. `192.0.0.171/32`: NAT64/DNS64 Discovery
+///
+/// [RFC 8880]: https://datatracker.ietf.org/doc/rfc8880/
+pub const RFC8880: RFC = RFC {
+  id: 8880,
+  ip_nets: &[IpNet::V4(IPV4_1), IpNet::V4(IPV4_2)],
+  ipv4_nets: &[IPV4_1, IPV4_2],
+  ipv6_nets: &[],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  for (idx, s) in ["192.0.0.170/32", "192.0.0.171/32"].iter().enumerate() {
+    let addr: Ipv4Net = s.parse().unwrap();
+    assert_eq!(RFC8880.ipv4_nets[idx], addr);
+  }
+
+  for block in RFC8880.blocks() {
+    assert!(RFC8880.contains(&block.net()));
+  }
+}
@@ -0,0 +1,49 @@
+use core::net::Ipv6Addr;
+
+use ipnet::{IpNet, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 2001:30::/28
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0x30, 0, 0, 0, 0, 0, 0), 28);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "Drone Remote ID Protocol Entity Tags (DETs) Prefix",
+    rfc: 9374,
+    section: None,
+    allocated: "2022-12",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(true),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 9374] DRIP Entity Tag (DET) for Unmanned Aircraft System Remote ID (UAS RID)
+///
+/// **Addresses:**
+/// - **IPv6:**
+///   1. `2001:30::/28`: Drone Remote ID Protocol Entity Tags (DETs) Prefix
+///
+/// [RFC 9374]: https://datatracker.ietf.org/doc/rfc9374/
+pub const RFC9374: RFC = RFC {
+  id: 9374,
+  ip_nets: &[IpNet::V6(IPV6_1)],
+  ipv4_nets: &[],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  let addr: Ipv6Net = "2001:30::/28".parse().unwrap();
+  assert_eq!(IPV6_1, addr);
+
+  for block in RFC9374.blocks() {
+    assert!(RFC9374.contains(&block.net()));
+  }
+}
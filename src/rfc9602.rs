@@ -0,0 +1,49 @@
+use core::net::Ipv6Addr;
+
+use ipnet::{IpNet, Ipv6Net};
+
+use super::{Block, RFC};
+
+/// 5f00::/16
+const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x5f00, 0, 0, 0, 0, 0, 0, 0), 16);
+
+const BLOCKS: &[Block] = &[
+  Block {
+    net: IpNet::V6(IPV6_1),
+    name: "Segment Routing (SRv6) SIDs",
+    rfc: 9602,
+    section: None,
+    allocated: "2024-04",
+    terminated: None,
+    source: true,
+    destination: true,
+    forwardable: true,
+    global: Some(false),
+    reserved_by_protocol: false,
+  },
+];
+
+/// [RFC 9602] Segment Routing over IPv6 (SRv6) Segment Identifiers in the IPv6 Addressing Architecture
+///
+/// **Addresses:**
+/// - **IPv6:**
+///   1. `5f00::/16`: Segment Routing (SRv6) SIDs
+///
+/// [RFC 9602]: https://datatracker.ietf.org/doc/rfc9602/
+pub const RFC9602: RFC = RFC {
+  id: 9602,
+  ip_nets: &[IpNet::V6(IPV6_1)],
+  ipv4_nets: &[],
+  ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
+};
+
+#[test]
+fn t() {
+  let addr: Ipv6Net = "5f00::/16".parse().unwrap();
+  assert_eq!(IPV6_1, addr);
+
+  for block in RFC9602.blocks() {
+    assert!(RFC9602.contains(&block.net()));
+  }
+}
@@ -2,11 +2,25 @@ use core::net::Ipv6Addr;
 
 use ipnet::{IpNet, Ipv6Net};
 
-use super::RFC;
+use super::{Block, RFC};
 
 /// 3fff::/20
 const IPV6_1: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x3fff, 0, 0, 0, 0, 0, 0, 0), 20);
 
+const BLOCKS: &[Block] = &[Block {
+  net: IpNet::V6(IPV6_1),
+  name: "Documentation",
+  rfc: 9637,
+  section: None,
+  allocated: "2024-07",
+  terminated: None,
+  source: false,
+  destination: false,
+  forwardable: false,
+  global: Some(false),
+  reserved_by_protocol: false,
+}];
+
 /// [RFC 9637] Expanding the IPv6 Documentation Space
 ///
 /// **Addresses:**
@@ -19,6 +33,7 @@ pub const RFC9637: RFC = RFC {
   ip_nets: &[IpNet::V6(IPV6_1)],
   ipv4_nets: &[],
   ipv6_nets: &[IPV6_1],
+  blocks: BLOCKS,
 };
 
 #[test]
@@ -1,24 +1,24 @@
 use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
+use super::{
+  ipv4_net_contains, ipv6_net_contains, rfc1122, rfc4291, rfc6890, sealed::Sealed, Ipv4Multicast,
+  Ipv6Multicast, MulticastScope, RFCs, RFC1112, RFC1918, RFC2544, RFC3849, RFC3927, RFC4193,
+  RFC5180, RFC5737, RFC6598, RFC919, RFC9637,
+};
+
+// The range-based classifiers are derived from the RFC tables, so they
+// cannot drift apart from `RFC::contains`.
+
 /// Returns `true` if the IPv4 address is in `127.0.0.0/8`.
 #[inline]
 pub const fn is_loopback_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, _, _, _] = ip.octets();
-  a == 127
+  ipv4_net_contains(rfc1122::LOOPBACK, ip)
 }
 
 /// Returns `true` if the IPv6 address is `::1/128`.
 #[inline]
 pub const fn is_loopback_ipv6_addr(ip: Ipv6Addr) -> bool {
-  let segments = ip.segments();
-  segments[0] == 0
-    && segments[1] == 0
-    && segments[2] == 0
-    && segments[3] == 0
-    && segments[4] == 0
-    && segments[5] == 0
-    && segments[6] == 0
-    && segments[7] == 1
+  ipv6_net_contains(rfc4291::LOOPBACK, ip)
 }
 
 /// Returns `true` if the IP address is loopback.
@@ -33,15 +33,13 @@ pub const fn is_loopback_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is in RFC 1918 private-use space.
 #[inline]
 pub const fn is_private_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, b, _, _] = ip.octets();
-  a == 10 || (a == 172 && b >= 16 && b <= 31) || (a == 192 && b == 168)
+  RFC1918.contains_v4_const(ip)
 }
 
 /// Returns `true` if the IPv6 address is in RFC 4193 unique-local space.
 #[inline]
 pub const fn is_unique_local_ipv6_addr(ip: Ipv6Addr) -> bool {
-  let segments = ip.segments();
-  segments[0] & 0xfe00 == 0xfc00
+  RFC4193.contains_v6_const(ip)
 }
 
 /// Returns `true` if the IP address is RFC 1918 private-use or RFC 4193 unique-local.
@@ -56,15 +54,13 @@ pub const fn is_private_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is in RFC 3927 link-local space.
 #[inline]
 pub const fn is_link_local_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, b, _, _] = ip.octets();
-  a == 169 && b == 254
+  RFC3927.contains_v4_const(ip)
 }
 
 /// Returns `true` if the IPv6 address is in `fe80::/10` link-local space.
 #[inline]
 pub const fn is_link_local_ipv6_addr(ip: Ipv6Addr) -> bool {
-  let segments = ip.segments();
-  segments[0] & 0xffc0 == 0xfe80
+  ipv6_net_contains(rfc4291::LINK_LOCAL, ip)
 }
 
 /// Returns `true` if the IP address is link-local.
@@ -79,18 +75,13 @@ pub const fn is_link_local_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is in an RFC 5737 documentation block.
 #[inline]
 pub const fn is_documentation_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, b, c, _] = ip.octets();
-  (a == 192 && b == 0 && c == 2)
-    || (a == 198 && b == 51 && c == 100)
-    || (a == 203 && b == 0 && c == 113)
+  RFC5737.contains_v4_const(ip)
 }
 
 /// Returns `true` if the IPv6 address is in an RFC 3849 or RFC 9637 documentation block.
 #[inline]
 pub const fn is_documentation_ipv6_addr(ip: Ipv6Addr) -> bool {
-  let segments = ip.segments();
-  (segments[0] == 0x2001 && segments[1] == 0x0db8)
-    || (segments[0] == 0x3fff && segments[1] <= 0x0fff)
+  RFC3849.contains_v6_const(ip) || RFC9637.contains_v6_const(ip)
 }
 
 /// Returns `true` if the IP address is reserved for documentation or examples.
@@ -105,15 +96,13 @@ pub const fn is_documentation_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is in RFC 2544 benchmarking space.
 #[inline]
 pub const fn is_benchmark_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, b, _, _] = ip.octets();
-  a == 198 && (b == 18 || b == 19)
+  RFC2544.contains_v4_const(ip)
 }
 
 /// Returns `true` if the IPv6 address is in RFC 5180 benchmarking space.
 #[inline]
 pub const fn is_benchmark_ipv6_addr(ip: Ipv6Addr) -> bool {
-  let segments = ip.segments();
-  segments[0] == 0x2001 && segments[1] == 0x0002 && segments[2] == 0
+  RFC5180.contains_v6_const(ip)
 }
 
 /// Returns `true` if the IP address is reserved for benchmarking.
@@ -128,8 +117,7 @@ pub const fn is_benchmark_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is in RFC 6598 shared address space.
 #[inline]
 pub const fn is_shared_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, b, _, _] = ip.octets();
-  a == 100 && b >= 64 && b <= 127
+  RFC6598.contains_v4_const(ip)
 }
 
 /// Returns `true` if the IP address is in shared address space.
@@ -144,15 +132,13 @@ pub const fn is_shared_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is multicast.
 #[inline]
 pub const fn is_multicast_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, _, _, _] = ip.octets();
-  a >= 224 && a <= 239
+  RFC1112.contains_v4_const(ip)
 }
 
 /// Returns `true` if the IPv6 address is multicast.
 #[inline]
 pub const fn is_multicast_ipv6_addr(ip: Ipv6Addr) -> bool {
-  let segments = ip.segments();
-  segments[0] & 0xff00 == 0xff00
+  ipv6_net_contains(rfc4291::MULTICAST, ip)
 }
 
 /// Returns `true` if the IP address is multicast.
@@ -167,22 +153,13 @@ pub const fn is_multicast_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is exactly `0.0.0.0/32`.
 #[inline]
 pub const fn is_unspecified_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, b, c, d] = ip.octets();
-  a == 0 && b == 0 && c == 0 && d == 0
+  ipv4_net_contains(rfc1122::THIS_HOST, ip)
 }
 
 /// Returns `true` if the IPv6 address is exactly `::/128`.
 #[inline]
 pub const fn is_unspecified_ipv6_addr(ip: Ipv6Addr) -> bool {
-  let segments = ip.segments();
-  segments[0] == 0
-    && segments[1] == 0
-    && segments[2] == 0
-    && segments[3] == 0
-    && segments[4] == 0
-    && segments[5] == 0
-    && segments[6] == 0
-    && segments[7] == 0
+  ipv6_net_contains(rfc4291::UNSPECIFIED, ip)
 }
 
 /// Returns `true` if the IP address is the exact unspecified address.
@@ -197,8 +174,7 @@ pub const fn is_unspecified_ip_addr(ip: IpAddr) -> bool {
 /// Returns `true` if the IPv4 address is exactly `255.255.255.255/32`.
 #[inline]
 pub const fn is_broadcast_ipv4_addr(ip: Ipv4Addr) -> bool {
-  let [a, b, c, d] = ip.octets();
-  a == 255 && b == 255 && c == 255 && d == 255
+  RFC919.contains_v4_const(ip)
 }
 
 /// Returns `true` if the IP address is the IPv4 limited broadcast address.
@@ -210,6 +186,281 @@ pub const fn is_broadcast_ip_addr(ip: IpAddr) -> bool {
   }
 }
 
+/// Returns `true` if the IPv6 interface identifier is RFC 4291 modified EUI-64,
+/// i.e. has `ff:fe` in its middle octets and likely embeds a MAC address.
+#[inline]
+pub const fn is_eui64_ipv6_addr(ip: Ipv6Addr) -> bool {
+  let octets = ip.octets();
+  octets[11] == 0xff && octets[12] == 0xfe
+}
+
+/// Returns `true` if the IPv4 address is in `240.0.0.0/4` reserved space,
+/// excluding the limited broadcast address.
+#[inline]
+pub const fn is_reserved_ipv4_addr(ip: Ipv4Addr) -> bool {
+  ipv4_net_contains(rfc6890::RESERVED, ip) && !is_broadcast_ipv4_addr(ip)
+}
+
+/// Returns `true` if the IPv6 address is unicast, i.e. not multicast.
+#[inline]
+pub const fn is_unicast_ipv6_addr(ip: Ipv6Addr) -> bool {
+  !is_multicast_ipv6_addr(ip)
+}
+
+/// Returns `true` if the IPv6 address is unicast with global scope: not
+/// unspecified, loopback, link-local, unique-local, documentation or
+/// benchmarking.
+#[inline]
+pub const fn is_unicast_global_ipv6_addr(ip: Ipv6Addr) -> bool {
+  is_unicast_ipv6_addr(ip)
+    && !is_unspecified_ipv6_addr(ip)
+    && !is_loopback_ipv6_addr(ip)
+    && !is_link_local_ipv6_addr(ip)
+    && !is_unique_local_ipv6_addr(ip)
+    && !is_documentation_ipv6_addr(ip)
+    && !is_benchmark_ipv6_addr(ip)
+}
+
+/// Returns `true` if the IPv4 address is globally reachable per the IANA
+/// special-purpose registry, or a multicast group of global scope.
+#[inline]
+pub fn is_global_ipv4_addr(ip: Ipv4Addr) -> bool {
+  match Ipv4Multicast::new(ip) {
+    Some(group) => matches!(group.scope(), MulticastScope::Global),
+    None => RFCs::block(&ip).map_or(true, |block| block.global() == Some(true)),
+  }
+}
+
+/// Returns `true` if the IPv6 address is globally reachable per the IANA
+/// special-purpose registry, or a multicast group of global scope.
+#[inline]
+pub fn is_global_ipv6_addr(ip: Ipv6Addr) -> bool {
+  match Ipv6Multicast::new(ip) {
+    Some(group) => matches!(group.scope(), MulticastScope::Global),
+    None => RFCs::block(&ip).map_or(true, |block| block.global() == Some(true)),
+  }
+}
+
+/// Returns `true` if the IP address is globally reachable.
+#[inline]
+pub fn is_global_ip_addr(ip: IpAddr) -> bool {
+  match ip {
+    IpAddr::V4(ip) => is_global_ipv4_addr(ip),
+    IpAddr::V6(ip) => is_global_ipv6_addr(ip),
+  }
+}
+
+/// Predicates of [`Ipv4Addr`] that are unstable in `std`, available on
+/// stable and in `no_std`.
+///
+/// While `std` keeps an inherent method of the same name unstable, calling
+/// it with method syntax triggers the `unstable_name_collisions` lint, so
+/// prefer the fully qualified syntax. Once `std` stabilizes it, method
+/// syntax resolves to the inherent method, whose semantics may differ.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::Ipv4AddrExt;
+/// use std::net::Ipv4Addr;
+///
+/// assert!(Ipv4AddrExt::is_global(&Ipv4Addr::new(1, 1, 1, 1)));
+/// assert!(!Ipv4AddrExt::is_global(&Ipv4Addr::new(100, 64, 0, 1)));
+/// assert!(Ipv4AddrExt::is_shared(&Ipv4Addr::new(100, 64, 0, 1)));
+/// // PCP anycast is globally reachable inside the non-global 192.0.0.0/24
+/// assert!(Ipv4AddrExt::is_global(&Ipv4Addr::new(192, 0, 0, 9)));
+/// ```
+pub trait Ipv4AddrExt: Sealed {
+  /// Returns `true` if the address is globally reachable, per the
+  /// "Globally Reachable" column of the IANA IPv4 Special-Purpose Address
+  /// Registry. Multicast addresses are global if their scope is.
+  fn is_global(&self) -> bool;
+
+  /// Returns `true` if the address is in `100.64.0.0/10` shared address
+  /// space ([RFC 6598](https://datatracker.ietf.org/doc/rfc6598/)).
+  fn is_shared(&self) -> bool;
+
+  /// Returns `true` if the address is in `198.18.0.0/15` benchmarking space
+  /// ([RFC 2544](https://datatracker.ietf.org/doc/rfc2544/)).
+  fn is_benchmarking(&self) -> bool;
+
+  /// Returns `true` if the address is in `240.0.0.0/4` reserved space,
+  /// excluding `255.255.255.255`
+  /// ([RFC 1112 §4](https://datatracker.ietf.org/doc/html/rfc1112#section-4)).
+  fn is_reserved(&self) -> bool;
+}
+
+impl Ipv4AddrExt for Ipv4Addr {
+  #[inline]
+  fn is_global(&self) -> bool {
+    is_global_ipv4_addr(*self)
+  }
+
+  #[inline]
+  fn is_shared(&self) -> bool {
+    is_shared_ipv4_addr(*self)
+  }
+
+  #[inline]
+  fn is_benchmarking(&self) -> bool {
+    is_benchmark_ipv4_addr(*self)
+  }
+
+  #[inline]
+  fn is_reserved(&self) -> bool {
+    is_reserved_ipv4_addr(*self)
+  }
+}
+
+/// Predicates of [`Ipv6Addr`] that are unstable in `std`, or stable only
+/// after the minimum supported Rust version, available on stable and in
+/// `no_std`.
+///
+/// See [`Ipv4AddrExt`] about the `unstable_name_collisions` lint.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{Ipv6AddrExt, MulticastScope};
+/// use std::net::Ipv6Addr;
+///
+/// let ip: Ipv6Addr = "2606:4700::1111".parse().unwrap();
+/// assert!(Ipv6AddrExt::is_global(&ip) && Ipv6AddrExt::is_unicast_global(&ip));
+///
+/// let ip: Ipv6Addr = "2001:db8::1".parse().unwrap();
+/// assert!(!Ipv6AddrExt::is_global(&ip) && Ipv6AddrExt::is_documentation(&ip));
+///
+/// let ip: Ipv6Addr = "ff05::1:3".parse().unwrap();
+/// assert_eq!(Some(MulticastScope::SiteLocal), Ipv6AddrExt::multicast_scope(&ip));
+/// ```
+pub trait Ipv6AddrExt: Sealed {
+  /// Returns `true` if the address is globally reachable, per the
+  /// "Globally Reachable" column of the IANA IPv6 Special-Purpose Address
+  /// Registry. Multicast addresses are global if their scope is.
+  fn is_global(&self) -> bool;
+
+  /// Returns `true` if the address is in `fc00::/7` unique-local space
+  /// ([RFC 4193](https://datatracker.ietf.org/doc/rfc4193/)).
+  fn is_unique_local(&self) -> bool;
+
+  /// Returns `true` if the address is in `fe80::/10` link-local unicast
+  /// space ([RFC 4291 §2.5.6](https://datatracker.ietf.org/doc/html/rfc4291#section-2.5.6)).
+  fn is_unicast_link_local(&self) -> bool;
+
+  /// Returns `true` if the address is unicast.
+  fn is_unicast(&self) -> bool;
+
+  /// Returns `true` if the address is unicast with global scope, see
+  /// [`is_unicast_global_ipv6_addr`].
+  fn is_unicast_global(&self) -> bool;
+
+  /// Returns `true` if the address is in `2001:db8::/32` or `3fff::/20`
+  /// documentation space ([RFC 3849](https://datatracker.ietf.org/doc/rfc3849/),
+  /// [RFC 9637](https://datatracker.ietf.org/doc/rfc9637/)).
+  fn is_documentation(&self) -> bool;
+
+  /// Returns `true` if the address is in `2001:2::/48` benchmarking space
+  /// ([RFC 5180](https://datatracker.ietf.org/doc/rfc5180/)).
+  fn is_benchmarking(&self) -> bool;
+
+  /// Returns `true` if the address is in `::ffff:0:0/96`.
+  fn is_ipv4_mapped(&self) -> bool;
+
+  /// Returns the scope of a multicast address, or `None` if the address is
+  /// not multicast.
+  fn multicast_scope(&self) -> Option<MulticastScope>;
+}
+
+impl Ipv6AddrExt for Ipv6Addr {
+  #[inline]
+  fn is_global(&self) -> bool {
+    is_global_ipv6_addr(*self)
+  }
+
+  #[inline]
+  fn is_unique_local(&self) -> bool {
+    is_unique_local_ipv6_addr(*self)
+  }
+
+  #[inline]
+  fn is_unicast_link_local(&self) -> bool {
+    is_link_local_ipv6_addr(*self)
+  }
+
+  #[inline]
+  fn is_unicast(&self) -> bool {
+    is_unicast_ipv6_addr(*self)
+  }
+
+  #[inline]
+  fn is_unicast_global(&self) -> bool {
+    is_unicast_global_ipv6_addr(*self)
+  }
+
+  #[inline]
+  fn is_documentation(&self) -> bool {
+    is_documentation_ipv6_addr(*self)
+  }
+
+  #[inline]
+  fn is_benchmarking(&self) -> bool {
+    is_benchmark_ipv6_addr(*self)
+  }
+
+  #[inline]
+  fn is_ipv4_mapped(&self) -> bool {
+    ipv6_net_contains(rfc4291::MAPPED, *self)
+  }
+
+  #[inline]
+  fn multicast_scope(&self) -> Option<MulticastScope> {
+    Ipv6Multicast::new(*self).map(|group| group.scope())
+  }
+}
+
+/// Predicates of [`IpAddr`] that are unstable in `std`, available on stable
+/// and in `no_std`.
+///
+/// See [`Ipv4AddrExt`] about the `unstable_name_collisions` lint.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::IpAddrExt;
+/// use std::net::IpAddr;
+///
+/// let ip: IpAddr = "198.51.100.7".parse().unwrap();
+/// assert!(!IpAddrExt::is_global(&ip) && IpAddrExt::is_documentation(&ip));
+/// ```
+pub trait IpAddrExt: Sealed {
+  /// Returns `true` if the address is globally reachable, see
+  /// [`Ipv4AddrExt::is_global`] and [`Ipv6AddrExt::is_global`].
+  fn is_global(&self) -> bool;
+
+  /// Returns `true` if the address is reserved for documentation.
+  fn is_documentation(&self) -> bool;
+
+  /// Returns `true` if the address is reserved for benchmarking.
+  fn is_benchmarking(&self) -> bool;
+}
+
+impl IpAddrExt for IpAddr {
+  #[inline]
+  fn is_global(&self) -> bool {
+    is_global_ip_addr(*self)
+  }
+
+  #[inline]
+  fn is_documentation(&self) -> bool {
+    is_documentation_ip_addr(*self)
+  }
+
+  #[inline]
+  fn is_benchmarking(&self) -> bool {
+    is_benchmark_ip_addr(*self)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -339,6 +590,212 @@ mod tests {
     );
   }
 
+  #[test]
+  fn eui64_ipv6_classifier() {
+    for s in ["fe80::2aa:ff:fe28:9c5a", "2001:db8::ff:fe00:0"] {
+      assert!(is_eui64_ipv6_addr(ipv6(s)), "{s} should be EUI-64");
+    }
+    for s in ["2001:db8::1", "2001:db8::ff:ff00:0", "::ffff:192.0.2.1"] {
+      assert!(!is_eui64_ipv6_addr(ipv6(s)), "{s} should not be EUI-64");
+    }
+  }
+
+  #[test]
+  fn global() {
+    for s in [
+      "1.1.1.1",
+      "192.0.0.9",
+      "192.0.0.10",
+      "192.31.196.1",
+      "224.0.1.1",
+      "2606:4700::1111",
+      "2001:1::1",
+      "2001:3::1",
+      "2001:20::1",
+      "ff0e::101",
+    ] {
+      assert!(IpAddrExt::is_global(&ip(s)), "{s} should be global");
+    }
+    for s in [
+      "0.0.0.0",
+      "10.0.0.1",
+      "100.64.0.1",
+      "127.0.0.1",
+      "169.254.0.1",
+      "192.0.0.1",
+      "192.0.2.1",
+      "198.18.0.1",
+      "224.0.0.1",
+      "239.255.255.250",
+      "240.0.0.1",
+      "255.255.255.255",
+      "::",
+      "::1",
+      "::ffff:8.8.8.8",
+      "64:ff9b:1::1",
+      "100::1",
+      "2001::1",
+      "2001:db8::1",
+      "2002::1",
+      "fc00::1",
+      "fe80::1",
+      "ff02::1",
+      "ff05::1:3",
+    ] {
+      assert!(!IpAddrExt::is_global(&ip(s)), "{s} should not be global");
+    }
+  }
+
+  #[test]
+  fn ext() {
+    let v4 = |s: &str| s.parse::<Ipv4Addr>().unwrap();
+    assert!(Ipv4AddrExt::is_shared(&v4("100.127.255.255")));
+    assert!(Ipv4AddrExt::is_benchmarking(&v4("198.19.0.1")));
+    assert!(Ipv4AddrExt::is_reserved(&v4("240.0.0.0")));
+    assert!(Ipv4AddrExt::is_reserved(&v4("255.255.255.254")));
+    assert!(!Ipv4AddrExt::is_reserved(&v4("255.255.255.255")));
+    assert!(!Ipv4AddrExt::is_reserved(&v4("239.255.255.255")));
+
+    assert!(Ipv6AddrExt::is_unicast_global(&ipv6("2001:4860::8888")));
+    assert!(Ipv6AddrExt::is_unicast_global(&ipv6("2002::1")));
+    for s in [
+      "::",
+      "::1",
+      "fe80::1",
+      "fd00::1",
+      "2001:db8::1",
+      "2001:2::1",
+      "ff0e::1",
+    ] {
+      assert!(!Ipv6AddrExt::is_unicast_global(&ipv6(s)), "{s}");
+    }
+    assert!(Ipv6AddrExt::is_unicast(&ipv6("fe80::1")));
+    assert!(!Ipv6AddrExt::is_unicast(&ipv6("ff02::1")));
+    assert!(Ipv6AddrExt::is_unique_local(&ipv6("fc00::1")));
+    assert!(Ipv6AddrExt::is_unicast_link_local(&ipv6("fe80::1")));
+    assert!(Ipv6AddrExt::is_ipv4_mapped(&ipv6("::ffff:10.0.0.1")));
+    assert!(!Ipv6AddrExt::is_ipv4_mapped(&ipv6("::10.0.0.1")));
+    assert!(Ipv6AddrExt::is_benchmarking(&ipv6("2001:2::1")));
+    assert!(Ipv6AddrExt::is_documentation(&ipv6("3fff::1")));
+    assert_eq!(
+      Ipv6AddrExt::multicast_scope(&ipv6("ff02::1")),
+      Some(MulticastScope::LinkLocal)
+    );
+    assert_eq!(Ipv6AddrExt::multicast_scope(&ipv6("fe80::1")), None);
+    assert!(IpAddrExt::is_benchmarking(&ip("198.18.0.1")));
+  }
+
+  #[test]
+  fn classifiers_match_rfc_tables() {
+    const _: () = assert!(is_private_ipv4_addr(Ipv4Addr::new(172, 31, 255, 255)));
+    const _: () = assert!(!is_documentation_ipv6_addr(Ipv6Addr::new(
+      0x3fff, 0x1000, 0, 0, 0, 0, 0, 0
+    )));
+
+    let rfc = |id: u32, ip: &IpAddr| crate::RFCs[id].contains(ip);
+    // the prefix is listed by the RFC and contains the address
+    let listed = |id: u32, net: &str, ip: &IpAddr| {
+      let net: ipnet::IpNet = net.parse().unwrap();
+      crate::RFCs[id].ip_nets().contains(&net) && net.contains(ip)
+    };
+    // the prefix is a registry block of the RFC and contains the address
+    let block = |id: u32, net: &str, ip: &IpAddr| {
+      let net: ipnet::IpNet = net.parse().unwrap();
+      crate::RFCs[id].blocks().iter().any(|b| b.net() == net) && net.contains(ip)
+    };
+    for s in [
+      "0.0.0.0",
+      "0.0.0.1",
+      "9.255.255.255",
+      "10.0.0.0",
+      "100.64.0.0",
+      "100.128.0.0",
+      "127.255.255.255",
+      "169.254.0.1",
+      "172.16.0.0",
+      "172.32.0.0",
+      "192.0.2.1",
+      "192.168.255.255",
+      "198.18.0.0",
+      "198.20.0.0",
+      "198.51.100.1",
+      "203.0.113.255",
+      "224.0.0.0",
+      "239.255.255.255",
+      "240.0.0.0",
+      "255.255.255.255",
+      "::",
+      "::1",
+      "::2",
+      "::ffff:0.0.0.0",
+      "::ffff:255.255.255.255",
+      "::1:ffff:0:0",
+      "2001:2::",
+      "2001:2:1::",
+      "2001:db8::1",
+      "3fff:fff::",
+      "3fff:1000::",
+      "fc00::",
+      "fe00::",
+      "fe80::",
+      "fec0::",
+      "ff00::",
+    ] {
+      let ip = ip(s);
+      assert_eq!(
+        is_private_ip_addr(ip),
+        rfc(1918, &ip) || rfc(4193, &ip),
+        "{s}"
+      );
+      assert_eq!(
+        is_link_local_ip_addr(ip),
+        rfc(3927, &ip) || listed(4291, "fe80::/10", &ip),
+        "{s}"
+      );
+      assert_eq!(
+        is_documentation_ip_addr(ip),
+        rfc(5737, &ip) || rfc(3849, &ip) || rfc(9637, &ip),
+        "{s}"
+      );
+      assert_eq!(
+        is_benchmark_ip_addr(ip),
+        rfc(2544, &ip) || rfc(5180, &ip),
+        "{s}"
+      );
+      assert_eq!(is_shared_ip_addr(ip), rfc(6598, &ip), "{s}");
+      assert_eq!(is_broadcast_ip_addr(ip), rfc(919, &ip), "{s}");
+      assert_eq!(
+        is_multicast_ip_addr(ip),
+        rfc(1112, &ip) || listed(4291, "ff00::/8", &ip),
+        "{s}"
+      );
+      assert_eq!(
+        is_loopback_ip_addr(ip),
+        listed(1122, "127.0.0.0/8", &ip) || listed(4291, "::1/128", &ip),
+        "{s}"
+      );
+      assert_eq!(
+        is_unspecified_ip_addr(ip),
+        block(1122, "0.0.0.0/32", &ip) || listed(4291, "::/128", &ip),
+        "{s}"
+      );
+      if let IpAddr::V4(v4) = ip {
+        assert_eq!(
+          is_reserved_ipv4_addr(v4),
+          listed(6890, "240.0.0.0/4", &ip) && !rfc(919, &ip),
+          "{s}"
+        );
+      }
+      if let IpAddr::V6(v6) = ip {
+        assert_eq!(
+          Ipv6AddrExt::is_ipv4_mapped(&v6),
+          listed(4291, "::ffff:0:0/96", &ip),
+          "{s}"
+        );
+      }
+    }
+  }
+
   #[test]
   fn benchmark_ipv6_classifier_matches_rfc5180() {
     for s in ["2001:2::", "2001:2:0:ffff:ffff:ffff:ffff:ffff"] {
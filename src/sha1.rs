@@ -0,0 +1,124 @@
+/// An incremental SHA-1 hasher ([RFC 3174]), used to derive identifiers.
+///
+/// [RFC 3174]: https://datatracker.ietf.org/doc/rfc3174/
+#[derive(Debug, Clone)]
+pub(crate) struct Sha1 {
+  state: [u32; 5],
+  block: [u8; 64],
+  len: u64,
+}
+
+impl Sha1 {
+  pub(crate) const fn new() -> Self {
+    Self {
+      state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+      block: [0; 64],
+      len: 0,
+    }
+  }
+
+  pub(crate) fn update(&mut self, mut data: &[u8]) {
+    while !data.is_empty() {
+      let offset = (self.len % 64) as usize;
+      let n = data.len().min(64 - offset);
+      self.block[offset..offset + n].copy_from_slice(&data[..n]);
+      self.len += n as u64;
+      data = &data[n..];
+      if offset + n == 64 {
+        self.compress();
+      }
+    }
+  }
+
+  pub(crate) fn finalize(mut self) -> [u8; 20] {
+    let bits = self.len.wrapping_mul(8);
+    self.update(&[0x80]);
+    while self.len % 64 != 56 {
+      self.update(&[0]);
+    }
+    self.update(&bits.to_be_bytes());
+
+    let mut digest = [0; 20];
+    for (chunk, word) in digest.chunks_exact_mut(4).zip(self.state) {
+      chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+  }
+
+  fn compress(&mut self) {
+    let mut w = [0u32; 80];
+    for (i, chunk) in self.block.chunks_exact(4).enumerate() {
+      w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    for i in 16..80 {
+      w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = self.state;
+    for (i, w) in w.iter().enumerate() {
+      let (f, k) = match i {
+        0..=19 => ((b & c) | (!b & d), 0x5a827999),
+        20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+        40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+        _ => (b ^ c ^ d, 0xca62c1d6),
+      };
+      let t = a
+        .rotate_left(5)
+        .wrapping_add(f)
+        .wrapping_add(e)
+        .wrapping_add(k)
+        .wrapping_add(*w);
+      e = d;
+      d = c;
+      c = b.rotate_left(30);
+      b = a;
+      a = t;
+    }
+
+    for (state, v) in self.state.iter_mut().zip([a, b, c, d, e]) {
+      *state = state.wrapping_add(v);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn digest(data: &[u8]) -> [u8; 20] {
+    let mut sha1 = Sha1::new();
+    sha1.update(data);
+    sha1.finalize()
+  }
+
+  #[test]
+  fn vectors() {
+    // RFC 3174 §7.3
+    assert_eq!(
+      digest(b"abc"),
+      [
+        0xa9, 0x99, 0x3e, 0x36, 0x47, 0x06, 0x81, 0x6a, 0xba, 0x3e, 0x25, 0x71, 0x78, 0x50, 0xc2,
+        0x6c, 0x9c, 0xd0, 0xd8, 0x9d
+      ]
+    );
+    assert_eq!(
+      digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+      [
+        0x84, 0x98, 0x3e, 0x44, 0x1c, 0x3b, 0xd2, 0x6e, 0xba, 0xae, 0x4a, 0xa1, 0xf9, 0x51, 0x29,
+        0xe5, 0xe5, 0x46, 0x70, 0xf1
+      ]
+    );
+
+    let mut sha1 = Sha1::new();
+    for _ in 0..10 {
+      sha1.update(b"0123456701234567012345670123456701234567012345670123456701234567");
+    }
+    assert_eq!(
+      sha1.finalize(),
+      [
+        0xde, 0xa3, 0x56, 0xa2, 0xcd, 0xdd, 0x90, 0xc7, 0xa7, 0xec, 0xed, 0xc5, 0xeb, 0xb5, 0x63,
+        0x93, 0x4f, 0x46, 0x04, 0x52
+      ]
+    );
+  }
+}
@@ -0,0 +1,238 @@
+use core::{fmt, net::Ipv6Addr};
+
+use ipnet::Ipv6Net;
+
+use super::{sha1::Sha1, subnet_anycast};
+
+/// The pseudorandom function `F()` of [RFC 7217 §5], computing a random
+/// identifier from the concatenation of its inputs.
+///
+/// Closures `Fn(&[&[u8]]) -> u64` implement this trait.
+///
+/// [RFC 7217 §5]: https://datatracker.ietf.org/doc/html/rfc7217#section-5
+pub trait Prf {
+  /// Returns the low 64 bits of the pseudorandom output for the
+  /// concatenation of `parts`.
+  fn compute(&self, parts: &[&[u8]]) -> u64;
+}
+
+impl<F: Fn(&[&[u8]]) -> u64> Prf for F {
+  #[inline]
+  fn compute(&self, parts: &[&[u8]]) -> u64 {
+    self(parts)
+  }
+}
+
+/// The default [`Prf`], keeping the low 64 bits of the SHA-1 digest, one of
+/// the options suggested by [RFC 7217 §5].
+///
+/// [RFC 7217 §5]: https://datatracker.ietf.org/doc/html/rfc7217#section-5
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Sha1Prf;
+
+impl Prf for Sha1Prf {
+  fn compute(&self, parts: &[&[u8]]) -> u64 {
+    let mut sha1 = Sha1::new();
+    for part in parts {
+      sha1.update(part);
+    }
+    let digest = sha1.finalize();
+    let mut low = [0; 8];
+    low.copy_from_slice(&digest[12..]);
+    u64::from_be_bytes(low)
+  }
+}
+
+/// A generator of [RFC 7217] stable, semantically opaque interface
+/// identifiers, the same for a given prefix and network but different
+/// across them.
+///
+/// The identifier is `F(Prefix | Net_Iface | Network_ID | DAD_Counter |
+/// secret_key)`, where the prefix is given by its first 64 bits and the
+/// DAD counter as one octet. Reserved identifiers ([`is_reserved_iid`]) are
+/// rejected, and should be handled as a duplicate address, by incrementing
+/// the DAD counter ([RFC 7217 §6]).
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::StableIid;
+///
+/// // the secret key should be at least 128 bits, generated at install time
+/// let stable = StableIid::new(b"0123456789abcdef");
+/// let prefix = "2001:db8:1::/64".parse().unwrap();
+///
+/// let a = stable.addr(prefix, b"eth0", b"", 0).unwrap();
+/// assert_eq!(a, stable.addr(prefix, b"eth0", b"", 0).unwrap());
+/// assert!(prefix.contains(&a));
+///
+/// // after a DAD failure
+/// assert_ne!(a, stable.addr(prefix, b"eth0", b"", 1).unwrap());
+/// ```
+///
+/// [RFC 7217]: https://datatracker.ietf.org/doc/rfc7217/
+/// [RFC 7217 §6]: https://datatracker.ietf.org/doc/html/rfc7217#section-6
+#[derive(Copy, Clone)]
+pub struct StableIid<'a, P = Sha1Prf> {
+  prf: P,
+  secret_key: &'a [u8],
+}
+
+impl<P: fmt::Debug> fmt::Debug for StableIid<'_, P> {
+  /// Formats the generator without its secret key.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("StableIid")
+      .field("prf", &self.prf)
+      .finish_non_exhaustive()
+  }
+}
+
+impl<'a> StableIid<'a> {
+  /// Creates a generator using [`Sha1Prf`].
+  #[inline]
+  pub const fn new(secret_key: &'a [u8]) -> Self {
+    Self::with_prf(Sha1Prf, secret_key)
+  }
+}
+
+impl<'a, P: Prf> StableIid<'a, P> {
+  /// Creates a generator using the given pseudorandom function.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::StableIid;
+  ///
+  /// // the total length of the inputs, 8 + 4 + 0 + 1 + 3 octets
+  /// let prf = |parts: &[&[u8]]| parts.iter().map(|p| p.len() as u64).sum::<u64>();
+  /// let stable = StableIid::with_prf(prf, b"key");
+  /// let prefix = "2001:db8::/64".parse().unwrap();
+  /// assert_eq!(Some(16), stable.iid(prefix, b"eth0", b"", 0));
+  /// ```
+  #[inline]
+  pub const fn with_prf(prf: P, secret_key: &'a [u8]) -> Self {
+    Self { prf, secret_key }
+  }
+
+  /// Returns the interface identifier, or `None` if it is reserved or the
+  /// prefix is longer than 64 bits.
+  pub fn iid(
+    &self,
+    prefix: Ipv6Net,
+    net_iface: &[u8],
+    network_id: &[u8],
+    dad_counter: u8,
+  ) -> Option<u64> {
+    if prefix.prefix_len() > 64 {
+      return None;
+    }
+
+    let prefix = ((prefix.network().to_bits() >> 64) as u64).to_be_bytes();
+    let iid = self.prf.compute(&[
+      &prefix,
+      net_iface,
+      network_id,
+      &[dad_counter],
+      self.secret_key,
+    ]);
+    if is_reserved_iid(iid) {
+      None
+    } else {
+      Some(iid)
+    }
+  }
+
+  /// Returns the address made of the first 64 bits of the prefix and the
+  /// interface identifier, or `None` if the identifier is reserved or the
+  /// prefix is longer than 64 bits.
+  pub fn addr(
+    &self,
+    prefix: Ipv6Net,
+    net_iface: &[u8],
+    network_id: &[u8],
+    dad_counter: u8,
+  ) -> Option<Ipv6Addr> {
+    self
+      .iid(prefix, net_iface, network_id, dad_counter)
+      .map(|iid| Ipv6Addr::from_bits(prefix.network().to_bits() >> 64 << 64 | iid as u128))
+  }
+}
+
+/// Returns `true` if the 64-bit interface identifier is reserved by the IANA
+/// Reserved IPv6 Interface Identifiers registry ([RFC 5453]): the
+/// subnet-router anycast identifier, the reserved subnet anycast identifiers
+/// of RFC 2526 and the IANA Ethernet block `0200:5eff:fe00:0000/40`.
+///
+/// [RFC 5453]: https://datatracker.ietf.org/doc/rfc5453/
+#[inline]
+pub const fn is_reserved_iid(iid: u64) -> bool {
+  subnet_anycast(Ipv6Addr::from_bits(iid as u128), 64).is_some() || iid >> 24 == 0x02_005e_fffe
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reserved() {
+    for iid in [
+      0,
+      0xfdff_ffff_ffff_ff80,
+      0xfdff_ffff_ffff_fffe,
+      0x0200_5eff_fe00_5213,
+      0x0200_5eff_feff_ffff,
+    ] {
+      assert!(is_reserved_iid(iid), "{iid:x}");
+    }
+    for iid in [
+      1,
+      0xfdff_ffff_ffff_ff7f,
+      0x0200_5eff_ff00_0000,
+      0x0000_5eff_fe00_5213,
+    ] {
+      assert!(!is_reserved_iid(iid), "{iid:x}");
+    }
+  }
+
+  #[test]
+  fn stable() {
+    let stable = StableIid::new(b"0123456789abcdef");
+    let prefix: Ipv6Net = "2001:db8:1::/64".parse().unwrap();
+    let iid = stable.iid(prefix, b"eth0", b"", 0).unwrap();
+    assert_eq!(
+      stable.iid("2001:db8:1::1/64".parse().unwrap(), b"eth0", b"", 0),
+      Some(iid)
+    );
+    assert_ne!(
+      stable.iid("2001:db8:2::/64".parse().unwrap(), b"eth0", b"", 0),
+      Some(iid)
+    );
+    assert_ne!(stable.iid(prefix, b"eth1", b"", 0), Some(iid));
+    assert_ne!(stable.iid(prefix, b"eth0", b"ssid", 0), Some(iid));
+    assert_ne!(
+      StableIid::new(b"fedcba9876543210").iid(prefix, b"eth0", b"", 0),
+      Some(iid)
+    );
+    assert_eq!(
+      stable.iid("2001:db8::/96".parse().unwrap(), b"eth0", b"", 0),
+      None
+    );
+
+    let addr = stable.addr(prefix, b"eth0", b"", 0).unwrap();
+    assert_eq!(addr.to_bits() as u64, iid);
+    assert_eq!(addr.to_bits() >> 64, prefix.network().to_bits() >> 64);
+
+    let anycast = StableIid::with_prf(|_: &[&[u8]]| 0xfdff_ffff_ffff_fffe, b"key");
+    assert_eq!(anycast.addr(prefix, b"eth0", b"", 0), None);
+
+    let counter = StableIid::with_prf(|parts: &[&[u8]]| parts[3][0] as u64, b"key");
+    assert_eq!(counter.iid(prefix, b"eth0", b"", 0), None);
+    assert_eq!(counter.iid(prefix, b"eth0", b"", 1), Some(1));
+  }
+
+  #[test]
+  fn debug_redacts_secret_key() {
+    let debug = std::format!("{:?}", StableIid::new(b"0123456789abcdef"));
+    assert_eq!(debug, "StableIid { prf: Sha1Prf, .. }");
+  }
+}
@@ -0,0 +1,166 @@
+use core::{
+  fmt,
+  net::{Ipv4Addr, Ipv6Addr},
+};
+
+use ipnet::Ipv6Net;
+
+/// A [RFC 4380] Teredo address.
+///
+/// ```text
+/// +-------------+-------------+-------+------+-------------+
+/// | Prefix      | Server IPv4 | Flags | Port | Client IPv4 |
+/// +-------------+-------------+-------+------+-------------+
+/// ```
+///
+/// The port and the client address are stored obfuscated, i.e. with all
+/// their bits inverted. The flags follow the layout `CRAAAAUG AAAAAAAA` of
+/// [RFC 5991 §2], where `C` is the cone bit and the `A` bits are random.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::TeredoAddr;
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+///
+/// let ip: Ipv6Addr = "2001:0:4136:e378:8000:63bf:3fff:fdd2".parse().unwrap();
+/// let teredo = TeredoAddr::new(ip).unwrap();
+/// assert_eq!(Ipv4Addr::new(65, 54, 227, 120), teredo.server());
+/// assert!(teredo.is_cone());
+/// assert_eq!(40000, teredo.port());
+/// assert_eq!(Ipv4Addr::new(192, 0, 2, 45), teredo.client());
+///
+/// let built = TeredoAddr::from_parts(teredo.server(), TeredoAddr::CONE, 40000, teredo.client());
+/// assert_eq!(teredo, built);
+/// ```
+///
+/// [RFC 4380]: https://datatracker.ietf.org/doc/rfc4380/
+/// [RFC 5991 §2]: https://datatracker.ietf.org/doc/html/rfc5991#section-2
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TeredoAddr(Ipv6Addr);
+
+impl TeredoAddr {
+  /// The Teredo prefix `2001::/32`.
+  pub const PREFIX: Ipv6Net = Ipv6Net::new_assert(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 32);
+
+  /// The cone bit of the flags, set when the client is behind a cone NAT.
+  pub const CONE: u16 = 0x8000;
+
+  /// The random bits of the flags.
+  pub const RANDOM: u16 = 0x3cff;
+
+  /// Returns a Teredo view of the address, or `None` if it is not in
+  /// `2001::/32`.
+  #[inline]
+  pub const fn new(ip: Ipv6Addr) -> Option<Self> {
+    let s = ip.segments();
+    if s[0] == 0x2001 && s[1] == 0 {
+      Some(Self(ip))
+    } else {
+      None
+    }
+  }
+
+  /// Builds a Teredo address from its parts, obfuscating the port and the
+  /// client address.
+  #[inline]
+  pub const fn from_parts(server: Ipv4Addr, flags: u16, port: u16, client: Ipv4Addr) -> Self {
+    let server = server.to_bits();
+    let client = !client.to_bits();
+    Self(Ipv6Addr::new(
+      0x2001,
+      0,
+      (server >> 16) as u16,
+      server as u16,
+      flags,
+      !port,
+      (client >> 16) as u16,
+      client as u16,
+    ))
+  }
+
+  /// Returns the Teredo address.
+  #[inline]
+  pub const fn addr(&self) -> Ipv6Addr {
+    self.0
+  }
+
+  /// Returns the IPv4 address of the Teredo server.
+  #[inline]
+  pub const fn server(&self) -> Ipv4Addr {
+    let o = self.0.octets();
+    Ipv4Addr::new(o[4], o[5], o[6], o[7])
+  }
+
+  /// Returns the flags.
+  #[inline]
+  pub const fn flags(&self) -> u16 {
+    self.0.segments()[4]
+  }
+
+  /// Returns `true` if the cone bit is set.
+  #[inline]
+  pub const fn is_cone(&self) -> bool {
+    self.flags() & Self::CONE != 0
+  }
+
+  /// Returns the random bits of the flags.
+  #[inline]
+  pub const fn random_bits(&self) -> u16 {
+    self.flags() & Self::RANDOM
+  }
+
+  /// Returns the de-obfuscated external UDP port of the client.
+  #[inline]
+  pub const fn port(&self) -> u16 {
+    !self.0.segments()[5]
+  }
+
+  /// Returns the de-obfuscated external IPv4 address of the client.
+  #[inline]
+  pub const fn client(&self) -> Ipv4Addr {
+    let o = self.0.octets();
+    Ipv4Addr::new(!o[12], !o[13], !o[14], !o[15])
+  }
+}
+
+impl From<TeredoAddr> for Ipv6Addr {
+  #[inline]
+  fn from(teredo: TeredoAddr) -> Self {
+    teredo.0
+  }
+}
+
+impl fmt::Display for TeredoAddr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn teredo() {
+    assert_eq!(TeredoAddr::PREFIX, "2001::/32".parse().unwrap());
+    assert!(TeredoAddr::new("2001:db8::1".parse().unwrap()).is_none());
+
+    // RFC 5991 §2: random bits set, cone bit clear
+    let teredo = TeredoAddr::from_parts(
+      Ipv4Addr::new(65, 54, 227, 120),
+      0x3cff,
+      65535,
+      Ipv4Addr::new(255, 255, 255, 255),
+    );
+    assert_eq!(
+      teredo.addr(),
+      "2001:0:4136:e378:3cff::".parse::<Ipv6Addr>().unwrap()
+    );
+    assert!(!teredo.is_cone());
+    assert_eq!(teredo.random_bits(), 0x3cff);
+    assert_eq!(teredo.port(), 65535);
+    assert_eq!(teredo.client(), Ipv4Addr::BROADCAST);
+    assert_eq!(TeredoAddr::new(teredo.addr()), Some(teredo));
+  }
+}
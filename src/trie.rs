@@ -0,0 +1,640 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::{sealed, Contains, Filter, RFCS};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A node of a binary trie, `0` marks a missing child since the root is never
+/// a child.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Node<V = Filter> {
+  children: [u32; 2],
+  value: V,
+}
+
+impl Node {
+  const EMPTY: Self = Self {
+    children: [0; 2],
+    value: Filter::empty(),
+  };
+}
+
+/// Iterates over the values of the nodes on the path of a key, from the root
+/// down to the first `len` bits of the key.
+pub(crate) struct Path<'a, V> {
+  nodes: &'a [Node<V>],
+  key: u128,
+  len: u8,
+  depth: u8,
+  node: Option<usize>,
+}
+
+impl<'a, V> Iterator for Path<'a, V> {
+  type Item = (u8, &'a V);
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    let node = self.node?;
+    let depth = self.depth;
+    self.node = if depth == self.len {
+      None
+    } else {
+      let bit = ((self.key >> (127 - depth)) & 1) as usize;
+      match self.nodes[node].children[bit] {
+        0 => None,
+        next => Some(next as usize),
+      }
+    };
+    self.depth += 1;
+    Some((depth, &self.nodes[node].value))
+  }
+}
+
+#[inline]
+fn path<V>(nodes: &[Node<V>], key: u128, len: u8) -> Path<'_, V> {
+  Path {
+    nodes,
+    key,
+    len,
+    depth: 0,
+    node: Some(0),
+  }
+}
+
+#[derive(Debug, Clone)]
+enum Nodes {
+  Static(&'static [Node]),
+  #[cfg(feature = "alloc")]
+  Owned(Vec<Node>),
+}
+
+impl Nodes {
+  #[inline]
+  fn as_slice(&self) -> &[Node] {
+    match self {
+      Self::Static(nodes) => nodes,
+      #[cfg(feature = "alloc")]
+      Self::Owned(nodes) => nodes,
+    }
+  }
+}
+
+/// Left-aligns an IPv4 prefix into the 128-bit key space.
+#[inline]
+const fn v4_key(addr: Ipv4Addr) -> u128 {
+  (addr.to_bits() as u128) << 96
+}
+
+/// Upper bound of the number of nodes needed for all of the built-in prefixes
+/// of one address family.
+const fn max_nodes(v6: bool) -> usize {
+  let mut n = 1;
+  let mut i = 0;
+  while i < RFCS.len() {
+    let rfc = &RFCS[i];
+    let mut j = 0;
+    if v6 {
+      while j < rfc.ipv6_nets.len() {
+        n += rfc.ipv6_nets[j].prefix_len() as usize;
+        j += 1;
+      }
+    } else {
+      while j < rfc.ipv4_nets.len() {
+        n += rfc.ipv4_nets[j].prefix_len() as usize;
+        j += 1;
+      }
+    }
+    i += 1;
+  }
+  n
+}
+
+/// Builds the trie of all of the built-in prefixes of one address family,
+/// returning the nodes and the number of nodes in use.
+const fn build<const N: usize>(v6: bool) -> ([Node; N], usize) {
+  let mut nodes = [Node::EMPTY; N];
+  let mut len = 1;
+  let mut i = 0;
+  while i < RFCS.len() {
+    let rfc = &RFCS[i];
+    let count = if v6 {
+      rfc.ipv6_nets.len()
+    } else {
+      rfc.ipv4_nets.len()
+    };
+
+    let mut j = 0;
+    while j < count {
+      let (key, prefix_len) = if v6 {
+        let net = rfc.ipv6_nets[j];
+        (net.addr().to_bits(), net.prefix_len())
+      } else {
+        let net = rfc.ipv4_nets[j];
+        (v4_key(net.addr()), net.prefix_len())
+      };
+
+      let mut node = 0;
+      let mut depth = 0;
+      while depth < prefix_len {
+        let bit = ((key >> (127 - depth)) & 1) as usize;
+        let next = nodes[node].children[bit] as usize;
+        if next == 0 {
+          nodes[node].children[bit] = len as u32;
+          node = len;
+          len += 1;
+        } else {
+          node = next;
+        }
+        depth += 1;
+      }
+      nodes[node].value = nodes[node].value.union(rfc.filter());
+      j += 1;
+    }
+    i += 1;
+  }
+  (nodes, len)
+}
+
+const fn shrink<const N: usize, const M: usize>(nodes: [Node; N]) -> [Node; M] {
+  let mut out = [Node::EMPTY; M];
+  let mut i = 0;
+  while i < M {
+    out[i] = nodes[i];
+    i += 1;
+  }
+  out
+}
+
+const V4_MAX: usize = max_nodes(false);
+const V4_LEN: usize = build::<V4_MAX>(false).1;
+const V4_NODES: &[Node] = &shrink::<V4_MAX, V4_LEN>(build::<V4_MAX>(false).0);
+
+const V6_MAX: usize = max_nodes(true);
+const V6_LEN: usize = build::<V6_MAX>(true).1;
+const V6_NODES: &[Node] = &shrink::<V6_MAX, V6_LEN>(build::<V6_MAX>(true).0);
+
+/// The result of walking the trie along one key.
+#[derive(Debug, Copy, Clone)]
+struct Walk {
+  /// The union of the filters of every prefix on the path.
+  filter: Filter,
+  /// The depth and filter of the longest prefix on the path.
+  longest: Option<(u8, Filter)>,
+}
+
+/// Walks the trie along the first `len` bits of the key, only considering the
+/// RFCs in `mask`.
+#[inline]
+fn walk(nodes: &[Node], key: u128, len: u8, mask: Filter) -> Walk {
+  path(nodes, key, len).fold(
+    Walk {
+      filter: Filter::empty(),
+      longest: None,
+    },
+    |mut walk, (depth, filter)| {
+      let filter = filter.intersection(mask);
+      if !filter.is_empty() {
+        walk.filter |= filter;
+        walk.longest = Some((depth, filter));
+      }
+      walk
+    },
+  )
+}
+
+/// A longest-prefix match of a [`PrefixTrie`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Match {
+  net: IpNet,
+  rfcs: Filter,
+}
+
+impl Match {
+  /// Returns the matched address block.
+  #[inline]
+  pub const fn net(&self) -> IpNet {
+    self.net
+  }
+
+  /// Returns the RFCs listing the matched address block.
+  #[inline]
+  pub const fn rfcs(&self) -> Filter {
+    self.rfcs
+  }
+}
+
+/// A compiled binary trie over the address blocks of the known RFCs.
+///
+/// Membership, classification and longest-prefix-match queries walk at most
+/// one node per prefix bit, so they run in O(prefix length) regardless of the
+/// number of RFCs.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{Filter, PrefixTrie};
+/// use std::net::Ipv4Addr;
+///
+/// let trie = PrefixTrie::builtin();
+///
+/// let m = trie.longest_match(&Ipv4Addr::new(192, 0, 0, 1)).unwrap();
+/// assert_eq!("192.0.0.0/29", m.net().to_string());
+/// assert!(m.rfcs().contains(Filter::RFC6890));
+///
+/// assert!(trie.contains(&Ipv4Addr::new(10, 0, 0, 1)));
+/// assert!(!trie.contains(&Ipv4Addr::new(8, 8, 8, 8)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrefixTrie {
+  filter: Filter,
+  v4: Nodes,
+  v6: Nodes,
+}
+
+impl PrefixTrie {
+  /// Returns the trie of all known RFCs, compiled at build time.
+  #[inline]
+  pub const fn builtin() -> Self {
+    Self {
+      filter: Filter::all(),
+      v4: Nodes::Static(V4_NODES),
+      v6: Nodes::Static(V6_NODES),
+    }
+  }
+
+  /// Compiles a trie of the RFCs in the given [`Filter`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Filter, PrefixTrie};
+  /// use std::net::Ipv4Addr;
+  ///
+  /// let trie = PrefixTrie::new(Filter::RFC1918 | Filter::RFC6598);
+  /// assert!(trie.contains(&Ipv4Addr::new(100, 64, 0, 1)));
+  /// assert!(!trie.contains(&Ipv4Addr::new(127, 0, 0, 1)));
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+  pub fn new(filter: Filter) -> Self {
+    let mut trie = Trie::<Filter>::new();
+    for rfc in RFCS.iter().filter(|rfc| filter.intersects(rfc.filter())) {
+      for net in rfc.ip_nets {
+        *trie.insert(*net) |= rfc.filter();
+      }
+    }
+
+    Self {
+      filter,
+      v4: Nodes::Owned(trie.v4),
+      v6: Nodes::Owned(trie.v6),
+    }
+  }
+
+  /// Returns the RFCs compiled into the trie.
+  #[inline]
+  pub const fn filter(&self) -> Filter {
+    self.filter
+  }
+
+  /// Returns `true` if any block of the trie contains the ip.
+  #[inline]
+  pub fn contains<T>(&self, ip: &T) -> bool
+  where
+    Self: Contains<T>,
+  {
+    Contains::contains(self, ip)
+  }
+
+  /// Returns the [`Filter`] of every RFC of the trie containing the ip.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Filter, PrefixTrie};
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let filter = PrefixTrie::builtin().classify(&Ipv6Addr::new(0x2001, 0x2, 0, 0, 0, 0, 0, 1));
+  /// assert!(filter.contains(Filter::RFC5180 | Filter::RFC6890));
+  /// ```
+  #[inline]
+  pub fn classify<T>(&self, ip: &T) -> Filter
+  where
+    Self: Lookup<T>,
+  {
+    Lookup::classify(self, ip)
+  }
+
+  /// Returns the most specific block of the trie containing the ip.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use iprfc::{Filter, PrefixTrie};
+  /// use std::net::Ipv6Addr;
+  ///
+  /// let m = PrefixTrie::builtin()
+  ///   .longest_match(&Ipv6Addr::new(0x2001, 0x2, 0, 0, 0, 0, 0, 1))
+  ///   .unwrap();
+  /// assert_eq!("2001:2::/48", m.net().to_string());
+  /// assert_eq!(Filter::RFC5180, m.rfcs());
+  /// ```
+  #[inline]
+  pub fn longest_match<T>(&self, ip: &T) -> Option<Match>
+  where
+    Self: Lookup<T>,
+  {
+    Lookup::longest_match(self, ip)
+  }
+}
+
+impl Default for PrefixTrie {
+  #[inline]
+  fn default() -> Self {
+    Self::builtin()
+  }
+}
+
+/// Inserts the first `len` bits of the key, returning the value of its node.
+#[cfg(feature = "alloc")]
+fn insert<V: Default>(nodes: &mut Vec<Node<V>>, key: u128, len: u8) -> &mut V {
+  let mut node = 0;
+  for depth in 0..len {
+    let bit = ((key >> (127 - depth)) & 1) as usize;
+    node = match nodes[node].children[bit] {
+      0 => {
+        let next = nodes.len();
+        nodes.push(Node {
+          children: [0; 2],
+          value: V::default(),
+        });
+        nodes[node].children[bit] = next as u32;
+        next
+      }
+      next => next as usize,
+    };
+  }
+  &mut nodes[node].value
+}
+
+/// A binary trie built at runtime, holding a `V` per prefix.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub(crate) struct Trie<V> {
+  v4: Vec<Node<V>>,
+  v6: Vec<Node<V>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Default> Trie<V> {
+  pub(crate) fn new() -> Self {
+    let root = || Node {
+      children: [0; 2],
+      value: V::default(),
+    };
+    Self {
+      v4: alloc::vec![root()],
+      v6: alloc::vec![root()],
+    }
+  }
+
+  /// Inserts the network, returning the value of its prefix.
+  pub(crate) fn insert(&mut self, net: IpNet) -> &mut V {
+    match net {
+      IpNet::V4(net) => insert(&mut self.v4, v4_key(net.addr()), net.prefix_len()),
+      IpNet::V6(net) => insert(&mut self.v6, net.addr().to_bits(), net.prefix_len()),
+    }
+  }
+
+  /// Returns the values of the prefixes containing the key, from the least
+  /// to the most specific one.
+  pub(crate) fn path(&self, key: Key) -> Path<'_, V> {
+    match key {
+      Key::V4(addr, len) => path(&self.v4, v4_key(addr), len),
+      Key::V6(addr, len) => path(&self.v6, addr.to_bits(), len),
+    }
+  }
+}
+
+/// An address or network, as the first `len` bits of an address.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum Key {
+  V4(Ipv4Addr, u8),
+  V6(Ipv6Addr, u8),
+}
+
+impl Key {
+  /// Returns the network made of the first `len` bits of the key.
+  #[inline]
+  pub(crate) fn net(self, len: u8) -> IpNet {
+    match self {
+      Self::V4(addr, _) => IpNet::V4(Ipv4Net::new_assert(addr, len).trunc()),
+      Self::V6(addr, _) => IpNet::V6(Ipv6Net::new_assert(addr, len).trunc()),
+    }
+  }
+
+  /// Walks the trie along the key, only considering the RFCs in `mask`.
+  #[inline]
+  fn walk(self, trie: &PrefixTrie, mask: Filter) -> (Filter, Option<Match>) {
+    let walk = match self {
+      Self::V4(addr, len) => walk(trie.v4.as_slice(), v4_key(addr), len, mask),
+      Self::V6(addr, len) => walk(trie.v6.as_slice(), addr.to_bits(), len, mask),
+    };
+    let longest = walk.longest.map(|(depth, rfcs)| Match {
+      net: self.net(depth),
+      rfcs,
+    });
+    (walk.filter, longest)
+  }
+}
+
+/// Converts an address or network to a [`Key`].
+pub(crate) trait ToKey {
+  fn key(&self) -> Key;
+}
+
+impl ToKey for Ipv4Addr {
+  #[inline]
+  fn key(&self) -> Key {
+    Key::V4(*self, 32)
+  }
+}
+
+impl ToKey for Ipv6Addr {
+  #[inline]
+  fn key(&self) -> Key {
+    Key::V6(*self, 128)
+  }
+}
+
+impl ToKey for Ipv4Net {
+  #[inline]
+  fn key(&self) -> Key {
+    Key::V4(self.network(), self.prefix_len())
+  }
+}
+
+impl ToKey for Ipv6Net {
+  #[inline]
+  fn key(&self) -> Key {
+    Key::V6(self.network(), self.prefix_len())
+  }
+}
+
+impl ToKey for IpAddr {
+  #[inline]
+  fn key(&self) -> Key {
+    match self {
+      Self::V4(addr) => addr.key(),
+      Self::V6(addr) => addr.key(),
+    }
+  }
+}
+
+impl ToKey for IpNet {
+  #[inline]
+  fn key(&self) -> Key {
+    match self {
+      Self::V4(net) => net.key(),
+      Self::V6(net) => net.key(),
+    }
+  }
+}
+
+/// Classification and longest-prefix-match queries of a [`PrefixTrie`].
+pub trait Lookup<T>: sealed::Sealed {
+  /// Returns the [`Filter`] of every RFC containing `T`.
+  fn classify(&self, t: &T) -> Filter;
+
+  /// Returns the most specific block containing `T`.
+  fn longest_match(&self, t: &T) -> Option<Match>;
+}
+
+macro_rules! lookup {
+  ($($ty:ty),+ $(,)?) => {
+    $(
+      impl Lookup<$ty> for PrefixTrie {
+        #[inline]
+        fn classify(&self, t: &$ty) -> Filter {
+          t.key().walk(self, self.filter).0
+        }
+
+        #[inline]
+        fn longest_match(&self, t: &$ty) -> Option<Match> {
+          t.key().walk(self, self.filter).1
+        }
+      }
+
+      impl Contains<$ty> for PrefixTrie {
+        #[inline]
+        fn contains(&self, t: &$ty) -> bool {
+          !Lookup::classify(self, t).is_empty()
+        }
+      }
+    )+
+  };
+}
+
+lookup!(Ipv4Addr, Ipv6Addr, Ipv4Net, Ipv6Net, IpAddr, IpNet);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::RFCs;
+
+  /// The first, last and adjacent addresses of every built-in block.
+  fn probes() -> std::vec::Vec<IpAddr> {
+    let mut out = std::vec::Vec::new();
+    for rfc in RFCs::iter() {
+      for net in rfc.ipv4_nets() {
+        let (lo, hi) = (net.network().to_bits(), net.broadcast().to_bits());
+        for bits in [lo.wrapping_sub(1), lo, hi, hi.wrapping_add(1)] {
+          out.push(IpAddr::V4(Ipv4Addr::from_bits(bits)));
+        }
+      }
+      for net in rfc.ipv6_nets() {
+        let (lo, hi) = (net.network().to_bits(), net.broadcast().to_bits());
+        for bits in [lo.wrapping_sub(1), lo, hi, hi.wrapping_add(1)] {
+          out.push(IpAddr::V6(Ipv6Addr::from_bits(bits)));
+        }
+      }
+    }
+    out
+  }
+
+  fn linear(ip: &IpAddr) -> Filter {
+    RFCs::iter()
+      .filter(|rfc| rfc.contains(ip))
+      .fold(Filter::empty(), |acc, rfc| acc | rfc.filter())
+  }
+
+  #[test]
+  fn builtin_matches_linear_scan() {
+    let trie = PrefixTrie::builtin();
+    for ip in probes() {
+      assert_eq!(trie.classify(&ip), linear(&ip), "{ip}");
+      assert_eq!(trie.contains(&ip), !linear(&ip).is_empty(), "{ip}");
+    }
+  }
+
+  #[test]
+  fn longest_match() {
+    let trie = PrefixTrie::builtin();
+    for (ip, net, rfcs) in [
+      (
+        "10.1.1.1",
+        "10.0.0.0/8",
+        Filter::RFC1918 | Filter::RFC3330 | Filter::RFC6890,
+      ),
+      (
+        "192.0.0.7",
+        "192.0.0.0/29",
+        Filter::RFC6333 | Filter::RFC7335 | Filter::RFC6890,
+      ),
+      (
+        "::1",
+        "::1/128",
+        Filter::RFC4291 | Filter::RFC6890 | Filter::FORWARDING_BLACKLIST,
+      ),
+      (
+        "2001:10::1",
+        "2001:10::/28",
+        Filter::RFC4843 | Filter::FORWARDING_BLACKLIST,
+      ),
+    ] {
+      let ip: IpAddr = ip.parse().unwrap();
+      let m = trie.longest_match(&ip).unwrap();
+      assert_eq!(m.net(), net.parse::<IpNet>().unwrap(), "{ip}");
+      assert_eq!(m.rfcs(), rfcs, "{ip}");
+    }
+
+    let ip: IpAddr = "8.8.8.8".parse().unwrap();
+    assert!(trie.longest_match(&ip).is_none());
+
+    let net: IpNet = "fe80::/64".parse().unwrap();
+    let m = trie.longest_match(&net).unwrap();
+    assert_eq!(m.net(), "fe80::/10".parse::<IpNet>().unwrap());
+
+    let net: IpNet = "fe00::/8".parse().unwrap();
+    assert!(!trie.contains(&net));
+  }
+
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn runtime_trie_matches_subset() {
+    let filter = Filter::RFC1918 | Filter::RFC4193 | Filter::FORWARDING_BLACKLIST;
+    let trie = PrefixTrie::new(filter);
+    assert_eq!(trie.filter(), filter);
+    for ip in probes() {
+      assert_eq!(trie.classify(&ip), linear(&ip) & filter, "{ip}");
+      assert_eq!(
+        trie.contains(&ip),
+        RFCs::filter(filter).contains(&ip),
+        "{ip}"
+      );
+    }
+  }
+}
@@ -0,0 +1,148 @@
+use core::{fmt, net::Ipv6Addr};
+
+use ipnet::Ipv6Net;
+
+use super::sha1::Sha1;
+
+/// An [RFC 4193] locally assigned Unique Local IPv6 prefix, a `/48` in
+/// `fd00::/8` made of a 40-bit pseudo-random Global ID.
+///
+/// ## Example
+///
+/// ```rust
+/// use iprfc::{MacAddr, UlaPrefix};
+///
+/// // 64-bit NTP timestamp and EUI-64 identifier of the system
+/// let timestamp = 0xe0e0_2c00_8000_0000;
+/// let eui64 = MacAddr::new(0x00, 0xaa, 0x00, 0x28, 0x9c, 0x5a).to_modified_eui64();
+/// let prefix = UlaPrefix::generate(timestamp, eui64);
+/// assert!(iprfc::is_unique_local_ipv6_addr(prefix.net().addr()));
+/// assert_eq!(48, prefix.net().prefix_len());
+///
+/// let subnet = prefix.subnet(1);
+/// assert_eq!(64, subnet.prefix_len());
+/// assert!(prefix.net().contains(&subnet));
+/// ```
+///
+/// [RFC 4193]: https://datatracker.ietf.org/doc/rfc4193/
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UlaPrefix(Ipv6Net);
+
+impl UlaPrefix {
+  /// Creates the prefix `fdXX:XXXX:XXXX::/48` from the low 40 bits of
+  /// `global_id`, e.g. drawn from a random number generator.
+  #[inline]
+  pub const fn from_global_id(global_id: u64) -> Self {
+    let bits = (0xfd << 120) | ((global_id & GLOBAL_ID_MASK) as u128) << 80;
+    Self(Ipv6Net::new_assert(Ipv6Addr::from_bits(bits), 48))
+  }
+
+  /// Generates a prefix with the algorithm of [RFC 4193 §3.2.2]: the Global
+  /// ID is the low 40 bits of the SHA-1 digest of the current time of day,
+  /// in 64-bit NTP format, followed by an EUI-64 identifier of the system.
+  ///
+  /// [RFC 4193 §3.2.2]: https://datatracker.ietf.org/doc/html/rfc4193#section-3.2.2
+  pub fn generate(ntp_timestamp: u64, eui64: u64) -> Self {
+    let mut sha1 = Sha1::new();
+    sha1.update(&ntp_timestamp.to_be_bytes());
+    sha1.update(&eui64.to_be_bytes());
+    Self::from_digest(sha1.finalize())
+  }
+
+  /// Generates a prefix from caller-supplied entropy: the Global ID is the
+  /// low 40 bits of its SHA-1 digest.
+  pub fn from_entropy(entropy: &[u8]) -> Self {
+    let mut sha1 = Sha1::new();
+    sha1.update(entropy);
+    Self::from_digest(sha1.finalize())
+  }
+
+  /// Returns a view of the network, or `None` if it is not a `/48` in
+  /// `fd00::/8`. Host bits are ignored.
+  #[inline]
+  pub const fn new(net: Ipv6Net) -> Option<Self> {
+    if net.prefix_len() != 48 || net.addr().octets()[0] != 0xfd {
+      return None;
+    }
+    Some(Self::from_global_id((net.addr().to_bits() >> 80) as u64))
+  }
+
+  /// Returns the prefix as a network.
+  #[inline]
+  pub const fn net(&self) -> Ipv6Net {
+    self.0
+  }
+
+  /// Returns the 40-bit Global ID.
+  #[inline]
+  pub const fn global_id(&self) -> u64 {
+    (self.0.addr().to_bits() >> 80) as u64 & GLOBAL_ID_MASK
+  }
+
+  /// Returns the `/64` subnet with the given 16-bit Subnet ID.
+  #[inline]
+  pub const fn subnet(&self, subnet_id: u16) -> Ipv6Net {
+    let bits = self.0.addr().to_bits() | (subnet_id as u128) << 64;
+    Ipv6Net::new_assert(Ipv6Addr::from_bits(bits), 64)
+  }
+
+  /// Returns all the 65536 `/64` subnets, in order.
+  #[inline]
+  pub fn subnets(&self) -> impl Iterator<Item = Ipv6Net> {
+    let prefix = *self;
+    (0..=u16::MAX).map(move |id| prefix.subnet(id))
+  }
+
+  fn from_digest(digest: [u8; 20]) -> Self {
+    let mut id = [0; 8];
+    id[3..].copy_from_slice(&digest[15..]);
+    Self::from_global_id(u64::from_be_bytes(id))
+  }
+}
+
+impl From<UlaPrefix> for Ipv6Net {
+  #[inline]
+  fn from(prefix: UlaPrefix) -> Self {
+    prefix.0
+  }
+}
+
+impl fmt::Display for UlaPrefix {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+const GLOBAL_ID_MASK: u64 = (1 << 40) - 1;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ula() {
+    let prefix = UlaPrefix::from_global_id(0xff12_3456_789a);
+    assert_eq!(prefix.net(), "fd12:3456:789a::/48".parse().unwrap());
+    assert_eq!(prefix.global_id(), 0x12_3456_789a);
+    assert_eq!(UlaPrefix::new(prefix.net()), Some(prefix));
+    assert_eq!(UlaPrefix::new("fc12:3456:789a::/48".parse().unwrap()), None);
+    assert_eq!(UlaPrefix::new("fd12:3456:789a::/56".parse().unwrap()), None);
+
+    assert_eq!(
+      prefix.subnet(0xbeef),
+      "fd12:3456:789a:beef::/64".parse().unwrap()
+    );
+    let mut subnets = prefix.subnets();
+    assert_eq!(subnets.next(), Some(prefix.subnet(0)));
+    assert_eq!(subnets.last(), Some(prefix.subnet(u16::MAX)));
+    assert_eq!(prefix.subnets().count(), 65536);
+
+    // SHA-1("abc") ends with 6c:9c:d0:d8:9d
+    assert_eq!(UlaPrefix::from_entropy(b"abc").global_id(), 0x6c_9cd0_d89d);
+
+    let a = UlaPrefix::generate(0xe0e0_2c00_8000_0000, 0x02aa_00ff_fe28_9c5a);
+    let b = UlaPrefix::generate(0xe0e0_2c00_8000_0001, 0x02aa_00ff_fe28_9c5a);
+    assert_ne!(a, b);
+    assert!(crate::RFC4193.contains(&a.net().addr()));
+  }
+}